@@ -1,7 +1,7 @@
 use cgmath::{Array, EuclideanSpace, Matrix4, Point3, Vector3};
 
 use crate::aligned_plane::{AlignedPlane, Axis};
-use crate::{Insertable, Query, Sphere, multiply_matrix4_and_point3};
+use crate::{Insertable, IntersectionClassification, Plane, Query, Sphere, multiply_matrix4_and_point3};
 
 /// An axis aligned bounding box.
 #[derive(Debug, Clone, Copy)]
@@ -110,6 +110,16 @@ impl AABB {
             && self.max.z >= other.min.z
     }
 
+    /// Check if this AABB is partially or fully on the front side of all
+    /// planes. With planes that point inwards, such as the planes of a
+    /// [`Frustum`](crate::Frustum), this is an intersection test with the
+    /// volume enclosed by the planes.
+    pub fn intersects_planes(&self, planes: &[Plane]) -> bool {
+        planes
+            .iter()
+            .all(|plane| plane.classify_aabb(self) != IntersectionClassification::Back)
+    }
+
     /// Creates a new AABB that is expanded by a given margin in all directions.
     pub fn expanded(&self, margin: f32) -> Self {
         AABB {
@@ -194,7 +204,7 @@ impl Query<AABB> for AABB {
 mod tests {
     use cgmath::{Matrix4, Point3, Vector3};
 
-    use crate::{AABB, AlignedPlane, Axis, Sphere};
+    use crate::{AABB, AlignedPlane, Axis, Plane, Sphere};
 
     #[test]
     fn test_new() {
@@ -255,6 +265,33 @@ mod tests {
         assert!(aabb_2.intersects_aabb(&aabb_3));
     }
 
+    #[test]
+    fn test_intersects_planes() {
+        // A cube from -10 to 10 on every axis, with all normals pointing inwards.
+        let planes = [
+            Plane::new(Vector3::new(1.0, 0.0, 0.0), Point3::new(-10.0, 0.0, 0.0)),
+            Plane::new(Vector3::new(-1.0, 0.0, 0.0), Point3::new(10.0, 0.0, 0.0)),
+            Plane::new(Vector3::new(0.0, 1.0, 0.0), Point3::new(0.0, -10.0, 0.0)),
+            Plane::new(Vector3::new(0.0, -1.0, 0.0), Point3::new(0.0, 10.0, 0.0)),
+            Plane::new(Vector3::new(0.0, 0.0, 1.0), Point3::new(0.0, 0.0, -10.0)),
+            Plane::new(Vector3::new(0.0, 0.0, -1.0), Point3::new(0.0, 0.0, 10.0)),
+        ];
+
+        let inside = AABB::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+        let outside = AABB::new(Point3::new(20.0, 0.0, 0.0), Point3::new(22.0, 2.0, 2.0));
+        let straddling = AABB::new(Point3::new(9.0, 0.0, 0.0), Point3::new(11.0, 2.0, 2.0));
+        let touching = AABB::new(Point3::new(10.0, 0.0, 0.0), Point3::new(12.0, 2.0, 2.0));
+        let enclosing = AABB::new(Point3::new(-50.0, -50.0, -50.0), Point3::new(50.0, 50.0, 50.0));
+        let behind_one_plane = AABB::new(Point3::new(0.0, 0.0, -15.0), Point3::new(1.0, 1.0, -11.0));
+
+        assert!(inside.intersects_planes(&planes));
+        assert!(!outside.intersects_planes(&planes));
+        assert!(straddling.intersects_planes(&planes));
+        assert!(touching.intersects_planes(&planes));
+        assert!(enclosing.intersects_planes(&planes));
+        assert!(!behind_one_plane.intersects_planes(&planes));
+    }
+
     #[test]
     fn test_expand() {
         let mut aabb = AABB::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
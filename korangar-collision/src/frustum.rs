@@ -59,6 +59,20 @@ impl Frustum {
         Self { planes }
     }
 
+    /// Replaces the far plane of the frustum with a plane that is `distance`
+    /// units away from the near plane. This is useful to limit the view
+    /// distance of an infinite projection.
+    pub fn with_far_plane(mut self, distance: f32) -> Self {
+        let near_plane = self.planes[4];
+        self.planes[5] = Plane::from_vec4(Vector4::new(
+            -near_plane.normal().x,
+            -near_plane.normal().y,
+            -near_plane.normal().z,
+            distance - near_plane.distance(),
+        ));
+        self
+    }
+
     /// Checks if a point is inside the frustum.
     pub fn contains_point(&self, point: Point3<f32>) -> bool {
         self.planes
@@ -69,9 +83,7 @@ impl Frustum {
     /// Test if the axis aligned bounding box is partially or fully inside the
     /// frustum.
     pub fn intersects_aabb(&self, aabb: &AABB) -> bool {
-        self.planes
-            .iter()
-            .all(|plane| plane.classify_aabb(aabb) != IntersectionClassification::Back)
+        aabb.intersects_planes(&self.planes)
     }
 
     /// Test if a sphere intersects with or is contained within the frustum.
@@ -286,4 +298,25 @@ mod tests {
         assert!(frustum.contains_point(Point3::new(0.0, 0.0, 10000.0)));
         assert!(frustum.contains_point(Point3::new(0.0, 0.0, 100000.0)));
     }
+
+    #[test]
+    fn test_limited_reverse_z_frustum() {
+        let near = 10.0;
+        let aspect_ratio = 16.0 / 9.0;
+        let fov = Deg(60.0).into();
+
+        let projection = perspective_reverse_lh(fov, aspect_ratio, near);
+        let view = Matrix4::look_at_lh(
+            Point3::new(0.0, 0.0, 50.0),
+            Point3::new(0.0, 0.0, 51.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+
+        let frustum = Frustum::new(projection * view, true).with_far_plane(100.0);
+
+        assert!(!frustum.contains_point(Point3::new(0.0, 0.0, 59.0)));
+        assert!(frustum.contains_point(Point3::new(0.0, 0.0, 61.0)));
+        assert!(frustum.contains_point(Point3::new(0.0, 0.0, 159.0)));
+        assert!(!frustum.contains_point(Point3::new(0.0, 0.0, 161.0)));
+    }
 }
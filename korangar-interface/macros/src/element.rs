@@ -1,6 +1,7 @@
 use proc_macro::TokenStream as InterfaceTokenStream;
+use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Attribute, DataEnum, DataStruct, Generics, Ident};
+use syn::{Attribute, DataEnum, DataStruct, Generics, Ident, parse_quote};
 
 use super::helper::state_element_helper;
 
@@ -12,7 +13,7 @@ pub fn derive_state_element_struct(
 ) -> InterfaceTokenStream {
     let (initializers, initializers_mut, is_unnamed, _window_title, _window_class) =
         state_element_helper(data_struct, attributes, name.to_string());
-    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let (_, type_generics, _) = generics.split_for_impl();
 
     // TODO: Instead get this from the proc macro.
     let impl_for = match std::env::var("CARGO_PKG_NAME").unwrap() == "korangar" {
@@ -21,6 +22,9 @@ pub fn derive_state_element_struct(
     };
 
     if let Some(impl_for) = impl_for {
+        let impl_generics = state_element_generics(&generics, impl_for.clone());
+        let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+
         if initializers.len() == 1 && is_unnamed {
             return quote! {
                 impl #impl_generics korangar_interface::element::StateElement<#impl_for> for #name #type_generics #where_clause {
@@ -90,9 +94,15 @@ pub fn derive_state_element_struct(
         .into();
     }
 
+    let mut impl_generics = state_element_generics(&generics, quote!(App));
+    impl_generics
+        .params
+        .push(parse_quote!(App: korangar_interface::application::Application));
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+
     if initializers.len() == 1 && is_unnamed {
         return quote! {
-            impl<App: korangar_interface::application::Application> #impl_generics korangar_interface::element::StateElement<App> for #name #type_generics #where_clause {
+            impl #impl_generics korangar_interface::element::StateElement<App> for #name #type_generics #where_clause {
                 type LayoutInfoMut = impl std::any::Any;
                 type ReturnMut<P>
                     = impl korangar_interface::element::Element<App, LayoutInfo = Self::LayoutInfoMut>
@@ -121,7 +131,7 @@ pub fn derive_state_element_struct(
     }
 
     quote! {
-        impl<App: korangar_interface::application::Application> #impl_generics korangar_interface::element::StateElement<App> for #name #type_generics #where_clause {
+        impl #impl_generics korangar_interface::element::StateElement<App> for #name #type_generics #where_clause {
             type LayoutInfoMut = impl std::any::Any;
             type ReturnMut<P>
                 = impl korangar_interface::element::Element<App, LayoutInfo = Self::LayoutInfoMut>
@@ -159,6 +169,22 @@ pub fn derive_state_element_struct(
     .into()
 }
 
+/// Requires every type parameter of the struct to be a state element itself,
+/// since the fields are displayed through their own elements.
+fn state_element_generics(generics: &Generics, application: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    let type_parameters: Vec<Ident> = generics.type_params().map(|parameter| parameter.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+
+    for parameter in type_parameters {
+        where_clause
+            .predicates
+            .push(parse_quote!(#parameter: korangar_interface::element::StateElement<#application>));
+    }
+
+    generics
+}
+
 pub fn derive_state_element_enum(_data_enum: DataEnum, generics: Generics, name: Ident) -> InterfaceTokenStream {
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
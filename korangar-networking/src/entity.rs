@@ -34,16 +34,16 @@ impl EntityData {
 impl From<EntityAppearedPacket> for EntityData {
     fn from(packet: EntityAppearedPacket) -> Self {
         Self {
-            entity_id: packet.entity_id,
-            movement_speed: packet.movement_speed,
-            job: packet.job,
-            head: packet.head,
+            entity_id: packet.base.entity_id,
+            movement_speed: packet.base.movement_speed,
+            job: packet.base.job,
+            head: packet.base.head,
             position: packet.position,
             destination: None,
-            health_points: packet.health_points,
-            maximum_health_points: packet.maximum_health_points,
-            head_direction: packet.head_direction as usize,
-            sex: packet.sex,
+            health_points: packet.status.health_points,
+            maximum_health_points: packet.status.maximum_health_points,
+            head_direction: packet.look.head_direction as usize,
+            sex: packet.look.sex,
         }
     }
 }
@@ -51,16 +51,16 @@ impl From<EntityAppearedPacket> for EntityData {
 impl From<EntityAppeared2Packet> for EntityData {
     fn from(packet: EntityAppeared2Packet) -> Self {
         Self {
-            entity_id: packet.entity_id,
-            movement_speed: packet.movement_speed,
-            job: packet.job,
-            head: packet.head,
+            entity_id: packet.base.entity_id,
+            movement_speed: packet.base.movement_speed,
+            job: packet.base.job,
+            head: packet.base.head,
             position: packet.position,
             destination: None,
-            health_points: packet.health_points,
-            maximum_health_points: packet.maximum_health_points,
-            head_direction: packet.head_direction as usize,
-            sex: packet.sex,
+            health_points: packet.status.health_points,
+            maximum_health_points: packet.status.maximum_health_points,
+            head_direction: packet.look.head_direction as usize,
+            sex: packet.look.sex,
         }
     }
 }
@@ -70,16 +70,16 @@ impl From<MovingEntityAppearedPacket> for EntityData {
         let (origin, destination) = packet.position.to_origin_destination();
 
         Self {
-            entity_id: packet.entity_id,
-            movement_speed: packet.movement_speed,
-            job: packet.job,
-            head: packet.head,
+            entity_id: packet.base.entity_id,
+            movement_speed: packet.base.movement_speed,
+            job: packet.base.job,
+            head: packet.base.head,
             position: origin,
             destination: Some(destination),
-            health_points: packet.health_points,
-            maximum_health_points: packet.maximum_health_points,
-            head_direction: packet.head_direction as usize,
-            sex: packet.sex,
+            health_points: packet.status.health_points,
+            maximum_health_points: packet.status.maximum_health_points,
+            head_direction: packet.look.head_direction as usize,
+            sex: packet.look.sex,
         }
     }
 }
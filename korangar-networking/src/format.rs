@@ -0,0 +1,50 @@
+/// Format an amount of zeny with thousands separators, e.g. `1,234,567z`.
+pub fn format_zeny(zeny: u32) -> String {
+    let digits = zeny.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
+        }
+
+        formatted.push(digit);
+    }
+
+    formatted.push('z');
+    formatted
+}
+
+/// Get the weight a character is carrying in percent of the maximum weight.
+/// Both values are expected in the same unit, so the tenths sent by the
+/// server can be passed as they are. A maximum weight of zero results in
+/// `0.0`.
+pub fn weight_percent(current: u32, maximum: u32) -> f32 {
+    match maximum {
+        0 => 0.0,
+        maximum => current as f32 / maximum as f32 * 100.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_zeny, weight_percent};
+
+    #[test]
+    fn zeny_thousands_separators() {
+        assert_eq!(format_zeny(0), "0z");
+        assert_eq!(format_zeny(999), "999z");
+        assert_eq!(format_zeny(1000), "1,000z");
+        assert_eq!(format_zeny(123456), "123,456z");
+        assert_eq!(format_zeny(1234567), "1,234,567z");
+        assert_eq!(format_zeny(u32::MAX), "4,294,967,295z");
+    }
+
+    #[test]
+    fn weight_percentage() {
+        assert_eq!(weight_percent(523, 1000), 52.3);
+        assert_eq!(weight_percent(1500, 1000), 150.0);
+        assert_eq!(weight_percent(0, 1000), 0.0);
+        assert_eq!(weight_percent(523, 0), 0.0);
+    }
+}
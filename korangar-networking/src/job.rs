@@ -0,0 +1,205 @@
+use ragnarok_packets::Job;
+
+macro_rules! job_names {
+    ($($job:ident => $name:literal,)*) => {
+        /// Every known job, in the order of `e_job` of rAthena.
+        const JOBS: &[Job] = &[$(Job::$job,)*];
+
+        /// Display name of a job. The match is exhaustive, so every variant of
+        /// [`Job`] needs a name.
+        fn display_name(job: Job) -> &'static str {
+            match job {
+                $(Job::$job => $name,)*
+            }
+        }
+    };
+}
+
+job_names! {
+    Novice => "Novice",
+    Swordman => "Swordman",
+    Magician => "Magician",
+    Archer => "Archer",
+    Acolyte => "Acolyte",
+    Merchant => "Merchant",
+    Thief => "Thief",
+    Knight => "Knight",
+    Priest => "Priest",
+    Wizard => "Wizard",
+    Blacksmith => "Blacksmith",
+    Hunter => "Hunter",
+    Assassin => "Assassin",
+    KnightMounted => "Knight (Mounted)",
+    Crusader => "Crusader",
+    Monk => "Monk",
+    Sage => "Sage",
+    Rogue => "Rogue",
+    Alchemist => "Alchemist",
+    Bard => "Bard",
+    Dancer => "Dancer",
+    CrusaderMounted => "Crusader (Mounted)",
+    Wedding => "Wedding",
+    SuperNovice => "Super Novice",
+    Gunslinger => "Gunslinger",
+    Ninja => "Ninja",
+    Christmas => "Christmas",
+    Summer => "Summer",
+    Hanbok => "Hanbok",
+    Oktoberfest => "Oktoberfest",
+    Summer2 => "Summer 2",
+    HighNovice => "High Novice",
+    HighSwordman => "High Swordman",
+    HighMagician => "High Magician",
+    HighArcher => "High Archer",
+    HighAcolyte => "High Acolyte",
+    HighMerchant => "High Merchant",
+    HighThief => "High Thief",
+    LordKnight => "Lord Knight",
+    HighPriest => "High Priest",
+    HighWizard => "High Wizard",
+    Whitesmith => "Whitesmith",
+    Sniper => "Sniper",
+    AssassinCross => "Assassin Cross",
+    LordKnightMounted => "Lord Knight (Mounted)",
+    Paladin => "Paladin",
+    Champion => "Champion",
+    Professor => "Professor",
+    Stalker => "Stalker",
+    Creator => "Creator",
+    Clown => "Clown",
+    Gypsy => "Gypsy",
+    PaladinMounted => "Paladin (Mounted)",
+    BabyNovice => "Baby Novice",
+    BabySwordman => "Baby Swordman",
+    BabyMagician => "Baby Magician",
+    BabyArcher => "Baby Archer",
+    BabyAcolyte => "Baby Acolyte",
+    BabyMerchant => "Baby Merchant",
+    BabyThief => "Baby Thief",
+    BabyKnight => "Baby Knight",
+    BabyPriest => "Baby Priest",
+    BabyWizard => "Baby Wizard",
+    BabyBlacksmith => "Baby Blacksmith",
+    BabyHunter => "Baby Hunter",
+    BabyAssassin => "Baby Assassin",
+    BabyKnightMounted => "Baby Knight (Mounted)",
+    BabyCrusader => "Baby Crusader",
+    BabyMonk => "Baby Monk",
+    BabySage => "Baby Sage",
+    BabyRogue => "Baby Rogue",
+    BabyAlchemist => "Baby Alchemist",
+    BabyBard => "Baby Bard",
+    BabyDancer => "Baby Dancer",
+    BabyCrusaderMounted => "Baby Crusader (Mounted)",
+    SuperBaby => "Super Baby",
+    Taekwon => "Taekwon",
+    StarGladiator => "Star Gladiator",
+    StarGladiatorUnion => "Star Gladiator (Union)",
+    SoulLinker => "Soul Linker",
+    Gangsi => "Gangsi",
+    DeathKnight => "Death Knight",
+    DarkCollector => "Dark Collector",
+    RuneKnight => "Rune Knight",
+    Warlock => "Warlock",
+    Ranger => "Ranger",
+    ArchBishop => "Arch Bishop",
+    Mechanic => "Mechanic",
+    GuillotineCross => "Guillotine Cross",
+    RuneKnightTrans => "Rune Knight (Trans)",
+    WarlockTrans => "Warlock (Trans)",
+    RangerTrans => "Ranger (Trans)",
+    ArchBishopTrans => "Arch Bishop (Trans)",
+    MechanicTrans => "Mechanic (Trans)",
+    GuillotineCrossTrans => "Guillotine Cross (Trans)",
+    RoyalGuard => "Royal Guard",
+    Sorcerer => "Sorcerer",
+    Minstrel => "Minstrel",
+    Wanderer => "Wanderer",
+    Sura => "Sura",
+    Genetic => "Genetic",
+    ShadowChaser => "Shadow Chaser",
+    RoyalGuardTrans => "Royal Guard (Trans)",
+    SorcererTrans => "Sorcerer (Trans)",
+    MinstrelTrans => "Minstrel (Trans)",
+    WandererTrans => "Wanderer (Trans)",
+    SuraTrans => "Sura (Trans)",
+    GeneticTrans => "Genetic (Trans)",
+    ShadowChaserTrans => "Shadow Chaser (Trans)",
+    RuneKnightDragon => "Rune Knight (Dragon)",
+    RuneKnightTransDragon => "Rune Knight (Trans, Dragon)",
+    RoyalGuardGryphon => "Royal Guard (Gryphon)",
+    RoyalGuardTransGryphon => "Royal Guard (Trans, Gryphon)",
+    RangerWarg => "Ranger (Warg)",
+    RangerTransWarg => "Ranger (Trans, Warg)",
+    MechanicMadoGear => "Mechanic (Mado Gear)",
+    MechanicTransMadoGear => "Mechanic (Trans, Mado Gear)",
+    BabyRuneKnight => "Baby Rune Knight",
+    BabyWarlock => "Baby Warlock",
+    BabyRanger => "Baby Ranger",
+    BabyArchBishop => "Baby Arch Bishop",
+    BabyMechanic => "Baby Mechanic",
+    BabyGuillotineCross => "Baby Guillotine Cross",
+    BabyRoyalGuard => "Baby Royal Guard",
+    BabySorcerer => "Baby Sorcerer",
+    BabyMinstrel => "Baby Minstrel",
+    BabyWanderer => "Baby Wanderer",
+    BabySura => "Baby Sura",
+    BabyGenetic => "Baby Genetic",
+    BabyShadowChaser => "Baby Shadow Chaser",
+    BabyRuneKnightDragon => "Baby Rune Knight (Dragon)",
+    BabyRoyalGuardGryphon => "Baby Royal Guard (Gryphon)",
+    BabyRangerWarg => "Baby Ranger (Warg)",
+    BabyMechanicMadoGear => "Baby Mechanic (Mado Gear)",
+    ExpandedSuperNovice => "Expanded Super Novice",
+    ExpandedSuperBaby => "Expanded Super Baby",
+    Kagerou => "Kagerou",
+    Oboro => "Oboro",
+    Rebellion => "Rebellion",
+    Summoner => "Summoner",
+}
+
+/// Get the display name of a job.
+pub fn job_name(job_id: u16) -> Option<&'static str> {
+    JOBS.iter().find(|job| **job as u16 == job_id).map(|job| display_name(*job))
+}
+
+/// Get the id of a job from its display name. The comparison ignores ASCII
+/// case.
+pub fn job_id(name: &str) -> Option<u16> {
+    JOBS.iter()
+        .find(|job| display_name(**job).eq_ignore_ascii_case(name))
+        .map(|job| *job as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JOBS, display_name, job_id, job_name};
+
+    #[test]
+    fn known_jobs() {
+        assert_eq!(job_name(0), Some("Novice"));
+        assert_eq!(job_name(1), Some("Swordman"));
+        assert_eq!(job_name(7), Some("Knight"));
+        assert_eq!(job_name(4218), Some("Summoner"));
+    }
+
+    #[test]
+    fn unknown_job() {
+        assert_eq!(job_name(4053), None);
+        assert_eq!(job_name(u16::MAX), None);
+        assert_eq!(job_id("Pirate"), None);
+    }
+
+    #[test]
+    fn reverse_lookup() {
+        assert_eq!(job_id("Knight"), Some(7));
+        assert_eq!(job_id("lord knight"), Some(4008));
+
+        for job in JOBS {
+            let name = display_name(*job);
+            let id = job_id(name).unwrap();
+
+            assert_eq!(job_name(id), Some(name));
+        }
+    }
+}
@@ -3,11 +3,15 @@
 
 mod entity;
 mod event;
+#[cfg(feature = "interface")]
+mod format;
 mod hotkey;
 mod items;
+mod job;
 mod message;
 mod packet_versions;
 mod server;
+mod skill;
 
 use std::net::{IpAddr, SocketAddr};
 use std::sync::{Arc, Mutex};
@@ -29,13 +33,17 @@ use tokio::task::JoinHandle;
 
 pub use self::entity::EntityData;
 pub use self::event::{DisconnectReason, NetworkEvent};
+#[cfg(feature = "interface")]
+pub use self::format::{format_zeny, weight_percent};
 pub use self::hotkey::HotkeyState;
 pub use self::items::{InventoryItem, InventoryItemDetails, ItemQuantity, NoMetadata, SellItem, ShopItem};
+pub use self::job::{job_id, job_name};
 pub use self::message::MessageColor;
 pub use self::packet_versions::SupportedPacketVersion;
 pub use self::server::{
     CharacterServerLoginData, LoginServerLoginData, NotConnectedError, UnifiedCharacterSelectionFailedReason, UnifiedLoginFailedReason,
 };
+pub use self::skill::{UNKNOWN_SKILL_NAME, skill_display_name, skill_name};
 use crate::server::NetworkTaskError;
 
 /// Buffer for networking events. This struct exists to reduce heap allocations
@@ -503,7 +511,7 @@ where
             // Always passing 100 seems to work fine for now, but it might cause
             // issues when connecting to something other than rAthena.
             ClientTick(100),
-            login_server_login_data.sex,
+            login_server_login_data.sex.into(),
         );
 
         self.packet_callback.outgoing_packet(&login_packet);
@@ -639,10 +647,10 @@ where
     }
 
     pub fn create_character(&mut self, slot: usize, name: String) -> Result<(), NotConnectedError> {
-        let hair_color = 0;
-        let hair_style = 0;
-        let start_job = 0;
-        let sex = Sex::Male;
+        let hair_color = HairColor(0);
+        let hair_style = HairStyle(0);
+        let start_job = Job::Novice;
+        let sex = CharacterSex::Male;
 
         match self.character_server_packet_version()? {
             SupportedPacketVersion::_20220406 => self.send_character_server_packet(CreateCharacterPacket::new(
@@ -876,13 +884,22 @@ where
             SupportedPacketVersion::_20220406 => self.send_map_server_packet(RequestStatUpPacket::new(stat_type)),
         }
     }
+
+    pub fn request_trait_stat_up(&mut self, stat_id: TraitStatId, amount: u16) -> Result<(), NotConnectedError> {
+        match self.map_server_packet_version()? {
+            SupportedPacketVersion::_20220406 => self.send_map_server_packet(IncreaseTraitStatPacket::new(stat_id, amount)),
+        }
+    }
 }
 
 #[cfg(test)]
 mod packet_handlers {
-    use ragnarok_packets::handler::NoPacketCallback;
+    use ragnarok_bytes::{ByteReader, ByteWriter};
+    use ragnarok_packets::handler::{HandlerResult, NoPacketCallback};
+    use ragnarok_packets::{AccountId, AuthToken, LoginServerLoginSuccessPacket, PacketExt, Sex};
 
-    use crate::{NetworkingSystem, SupportedPacketVersion};
+    use crate::event::NetworkEventList;
+    use crate::{NetworkEvent, NetworkingSystem, SupportedPacketVersion, UnifiedLoginFailedReason};
 
     #[test]
     fn login_server() {
@@ -890,6 +907,28 @@ mod packet_handlers {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn login_with_invalid_sex() {
+        let mut packet_handler =
+            NetworkingSystem::create_login_server_packet_handler(NoPacketCallback, SupportedPacketVersion::_20220406).unwrap();
+
+        let packet = LoginServerLoginSuccessPacket::new(1, AccountId(2000000), 2, Sex::Server, AuthToken([0; 17]), Vec::new());
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+
+        let bytes = byte_writer.into_inner();
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+
+        let HandlerResult::Ok(NetworkEventList(events)) = packet_handler.process_one(&mut byte_reader) else {
+            panic!("failed to process the login packet");
+        };
+
+        assert!(matches!(events.as_slice(), [NetworkEvent::LoginServerConnectionFailed {
+            reason: UnifiedLoginFailedReason::InvalidSex,
+            ..
+        }]));
+    }
+
     #[test]
     fn character_server() {
         let result = NetworkingSystem::create_character_server_packet_handler(NoPacketCallback, SupportedPacketVersion::_20220406);
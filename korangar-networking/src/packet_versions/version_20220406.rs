@@ -19,15 +19,23 @@ pub fn register_login_server_packets<Callback>(
 where
     Callback: PacketCallback,
 {
-    packet_handler.register(|packet: LoginServerLoginSuccessPacket| NetworkEvent::LoginServerConnected {
-        character_servers: packet.character_server_information,
-        login_data: LoginServerLoginData {
-            account_id: packet.account_id,
-            login_id1: packet.login_id1,
-            login_id2: packet.login_id2,
-            sex: packet.sex,
+    packet_handler.register(
+        |packet: LoginServerLoginSuccessPacket| match CharacterSex::try_from(packet.sex) {
+            Ok(sex) => NetworkEvent::LoginServerConnected {
+                character_servers: packet.character_server_information,
+                login_data: LoginServerLoginData {
+                    account_id: packet.account_id,
+                    login_id1: packet.login_id1,
+                    login_id2: packet.login_id2,
+                    sex,
+                },
+            },
+            Err(InvalidCharacterSexError) => NetworkEvent::LoginServerConnectionFailed {
+                reason: UnifiedLoginFailedReason::InvalidSex,
+                message: "Login server sent an invalid sex",
+            },
         },
-    })?;
+    )?;
     packet_handler.register(|packet: LoginFailedPacket| {
         let (reason, message) = match packet.reason {
             LoginFailedReason::ServerClosed => (UnifiedLoginFailedReason::ServerClosed, "Server closed"),
@@ -236,12 +244,9 @@ where
             starting_timestamp,
         }
     })?;
-    packet_handler.register(|packet: ChangeMapPacket| {
-        let ChangeMapPacket { map_name, position } = packet;
-
-        let map_name = map_name.replace(".gat", "");
-
-        NetworkEvent::ChangeMap { map_name, position }
+    packet_handler.register(|packet: ChangeMapPacket| NetworkEvent::ChangeMap {
+        map_name: packet.normalized_map_name().to_owned(),
+        position: packet.position,
     })?;
     packet_handler.register(|packet: ResurrectionPacket| NetworkEvent::ResurrectPlayer {
         entity_id: packet.entity_id,
@@ -294,8 +299,12 @@ where
     packet_handler.register({
         let inventory_items = inventory_items.clone();
 
-        move |_: InventoyStartPacket| {
-            *inventory_items.borrow_mut() = Some(Vec::new());
+        move |packet: InventoyStartPacket| {
+            // TODO: Handle the contents of the cart and storage.
+            if packet.inventory_type == InventoryType::Inventory {
+                *inventory_items.borrow_mut() = Some(Vec::new());
+            }
+
             NoNetworkEvents
         }
     })?;
@@ -303,6 +312,10 @@ where
         let inventory_items = inventory_items.clone();
 
         move |packet: RegularItemListPacket| {
+            if packet.inventory_type != InventoryType::Inventory {
+                return NoNetworkEvents;
+            }
+
             inventory_items
                 .borrow_mut()
                 .as_mut()
@@ -340,6 +353,10 @@ where
         let inventory_items = inventory_items.clone();
 
         move |packet: EquippableItemListPacket| {
+            if packet.inventory_type != InventoryType::Inventory {
+                return NoNetworkEvents;
+            }
+
             inventory_items
                 .borrow_mut()
                 .as_mut()
@@ -388,9 +405,13 @@ where
     packet_handler.register({
         let inventory_items = inventory_items.clone();
 
-        move |_: InventoyEndPacket| {
+        move |packet: InventoyEndPacket| {
+            if packet.inventory_type != InventoryType::Inventory {
+                return None;
+            }
+
             let items = inventory_items.borrow_mut().take().expect("Unexpected inventory end packet");
-            NetworkEvent::SetInventory { items }
+            Some(NetworkEvent::SetInventory { items })
         }
     })?;
     packet_handler.register_noop::<EquippableSwitchItemListPacket>()?;
@@ -687,7 +708,7 @@ where
         },
     })?;
     packet_handler.register_noop::<UseSkillSuccessPacket>()?;
-    packet_handler.register_noop::<ToUseSkillSuccessPacket>()?;
+    packet_handler.register_noop::<SkillFailedPacket>()?;
     packet_handler.register(|packet: NotifySkillUnitPacket| {
         let NotifySkillUnitPacket {
             entity_id,
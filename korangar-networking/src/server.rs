@@ -1,6 +1,6 @@
 use std::net::{IpAddr, SocketAddr};
 
-use ragnarok_packets::{AccountId, CharacterId, Sex};
+use ragnarok_packets::{AccountId, CharacterId, CharacterSex};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
 use crate::SupportedPacketVersion;
@@ -11,7 +11,7 @@ pub struct LoginServerLoginData {
     pub account_id: AccountId,
     pub login_id1: u32,
     pub login_id2: u32,
-    pub sex: Sex,
+    pub sex: CharacterSex,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -28,6 +28,7 @@ pub enum UnifiedLoginFailedReason {
     LoginProhibitedUntil,
     ServerFull,
     CompanyAccountLimitReached,
+    InvalidSex,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,137 @@
+use ragnarok_packets::SkillId;
+
+/// Name that is displayed for skills that are not in the table.
+pub const UNKNOWN_SKILL_NAME: &str = "Unknown";
+
+/// Skill ids and display names of common skills, mirroring `e_skill` of
+/// rAthena.
+const SKILL_NAMES: &[(u16, &str)] = &[
+    (1, "Basic Skill"),
+    (2, "Sword Mastery"),
+    (3, "Two-Handed Sword Mastery"),
+    (4, "Increase HP Recovery"),
+    (5, "Bash"),
+    (6, "Provoke"),
+    (7, "Magnum Break"),
+    (8, "Endure"),
+    (9, "Increase SP Recovery"),
+    (10, "Sight"),
+    (11, "Napalm Beat"),
+    (12, "Safety Wall"),
+    (13, "Soul Strike"),
+    (14, "Cold Bolt"),
+    (15, "Frost Diver"),
+    (16, "Stone Curse"),
+    (17, "Fire Ball"),
+    (18, "Fire Wall"),
+    (19, "Fire Bolt"),
+    (20, "Lightning Bolt"),
+    (21, "Thunderstorm"),
+    (22, "Divine Protection"),
+    (23, "Demon Bane"),
+    (24, "Ruwach"),
+    (25, "Pneuma"),
+    (26, "Teleport"),
+    (27, "Warp Portal"),
+    (28, "Heal"),
+    (29, "Increase AGI"),
+    (30, "Decrease AGI"),
+    (31, "Aqua Benedicta"),
+    (32, "Signum Crucis"),
+    (33, "Angelus"),
+    (34, "Blessing"),
+    (35, "Cure"),
+    (36, "Enlarge Weight Limit"),
+    (37, "Discount"),
+    (38, "Overcharge"),
+    (39, "Pushcart"),
+    (40, "Item Appraisal"),
+    (41, "Vending"),
+    (42, "Mammonite"),
+    (43, "Owl's Eye"),
+    (44, "Vulture's Eye"),
+    (45, "Improve Concentration"),
+    (46, "Double Strafe"),
+    (47, "Arrow Shower"),
+    (48, "Double Attack"),
+    (49, "Improve Dodge"),
+    (50, "Steal"),
+    (51, "Hiding"),
+    (52, "Envenom"),
+    (53, "Detoxify"),
+    (54, "Resurrection"),
+    (55, "Spear Mastery"),
+    (56, "Pierce"),
+    (57, "Brandish Spear"),
+    (58, "Spear Stab"),
+    (59, "Spear Boomerang"),
+    (60, "Two-Hand Quicken"),
+    (61, "Counter Attack"),
+    (62, "Bowling Bash"),
+    (63, "Peco Peco Riding"),
+    (64, "Cavalier Mastery"),
+    (65, "Mace Mastery"),
+    (66, "Impositio Manus"),
+    (67, "Suffragium"),
+    (68, "Aspersio"),
+    (69, "B.S Sacramenti"),
+    (70, "Sanctuary"),
+    (71, "Slow Poison"),
+    (72, "Status Recovery"),
+    (73, "Kyrie Eleison"),
+    (74, "Magnificat"),
+    (75, "Gloria"),
+    (76, "Lex Divina"),
+    (77, "Turn Undead"),
+    (78, "Lex Aeterna"),
+    (79, "Magnus Exorcismus"),
+    (80, "Fire Pillar"),
+    (81, "Sightrasher"),
+    (83, "Meteor Storm"),
+    (84, "Jupitel Thunder"),
+    (85, "Lord of Vermilion"),
+    (86, "Water Ball"),
+    (87, "Ice Wall"),
+    (88, "Frost Nova"),
+    (89, "Storm Gust"),
+    (90, "Earth Spike"),
+    (91, "Heaven's Drive"),
+    (92, "Quagmire"),
+    (93, "Sense"),
+    (142, "First Aid"),
+    (143, "Play Dead"),
+];
+
+/// Get the display name of a skill.
+pub fn skill_name(skill_id: SkillId) -> Option<&'static str> {
+    SKILL_NAMES.iter().find(|(id, _)| *id == skill_id.0).map(|(_, name)| *name)
+}
+
+/// Get the display name of a skill, falling back to [`UNKNOWN_SKILL_NAME`]
+/// for skills that are not in the table.
+pub fn skill_display_name(skill_id: SkillId) -> &'static str {
+    skill_name(skill_id).unwrap_or(UNKNOWN_SKILL_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use ragnarok_packets::SkillId;
+
+    use super::{UNKNOWN_SKILL_NAME, skill_display_name, skill_name};
+
+    #[test]
+    fn known_skills() {
+        assert_eq!(skill_name(SkillId(5)), Some("Bash"));
+        assert_eq!(skill_name(SkillId(19)), Some("Fire Bolt"));
+        assert_eq!(skill_name(SkillId(28)), Some("Heal"));
+        assert_eq!(skill_name(SkillId(89)), Some("Storm Gust"));
+    }
+
+    #[test]
+    fn unknown_skill() {
+        assert_eq!(skill_name(SkillId(82)), None);
+        assert_eq!(skill_name(SkillId(u16::MAX)), None);
+        assert_eq!(skill_display_name(SkillId(u16::MAX)), UNKNOWN_SKILL_NAME);
+        assert_eq!(skill_display_name(SkillId(5)), "Bash");
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, print_debug};
-use wgpu::{Adapter, Features, Limits, TextureFormat, TextureFormatFeatureFlags};
+use wgpu::{Adapter, DownlevelFlags, Features, Limits, TextureFormat, TextureFormatFeatureFlags};
 
 use crate::graphics::{Msaa, RENDER_TO_TEXTURE_DEPTH_FORMAT, RENDER_TO_TEXTURE_FORMAT};
 
@@ -10,6 +10,11 @@ pub const MAX_BINDING_ARRAY_ELEMENTS_PER_SHADER_STAGE: u32 = 10240;
 /// available.
 pub const MAX_TEXTURE_SIZE: u32 = 8192;
 
+/// The maximum anisotropy of samplers. WebGPU doesn't expose the limit of the
+/// device and caps all values at 16, so this is only used if the adapter
+/// supports anisotropic filtering at all.
+pub const MAX_SAMPLER_ANISOTROPY: u16 = 16;
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum BindlessSupport {
     /// Full bindless support.
@@ -26,6 +31,7 @@ pub struct Capabilities {
     multidraw_indirect: bool,
     clamp_to_border: bool,
     texture_compression: bool,
+    max_sampler_anisotropy: u16,
     #[cfg(feature = "debug")]
     polygon_mode_line: bool,
     required_features: Features,
@@ -36,6 +42,7 @@ impl Capabilities {
     pub fn from_adapter(adapter: &Adapter) -> Self {
         let adapter_features = adapter.features();
         let adapter_limits = adapter.limits();
+        let adapter_downlevel_flags = adapter.get_downlevel_capabilities().flags;
 
         // We need to test all textures that we use for MSAA which sample count they
         // support.
@@ -53,6 +60,7 @@ impl Capabilities {
             multidraw_indirect: false,
             clamp_to_border: false,
             texture_compression: false,
+            max_sampler_anisotropy: 1,
             #[cfg(feature = "debug")]
             polygon_mode_line: false,
             required_features: Features::empty(),
@@ -111,6 +119,10 @@ impl Capabilities {
             capabilities.required_features |= Features::TEXTURE_COMPRESSION_BC;
         }
 
+        if adapter_downlevel_flags.contains(DownlevelFlags::ANISOTROPIC_FILTERING) {
+            capabilities.max_sampler_anisotropy = MAX_SAMPLER_ANISOTROPY;
+        }
+
         #[cfg(feature = "debug")]
         if adapter_features.contains(Features::POLYGON_MODE_LINE) {
             capabilities.polygon_mode_line = true;
@@ -142,6 +154,13 @@ impl Capabilities {
         MAX_BINDING_ARRAY_ELEMENTS_PER_SHADER_STAGE
     }
 
+    /// Returns the maximum anisotropy that can be used when creating a
+    /// sampler. This is `1` if the adapter doesn't support anisotropic
+    /// filtering.
+    pub fn get_max_sampler_anisotropy(&self) -> u16 {
+        self.max_sampler_anisotropy
+    }
+
     /// Returns `true` if the backend supports all features needed for multidraw
     /// indirect.
     pub fn supports_multidraw_indirect(&self) -> bool {
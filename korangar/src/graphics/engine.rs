@@ -20,8 +20,8 @@ use winit::window::Window;
 use super::BindlessSupport;
 use super::{
     AntiAliasingResources, Capabilities, DirectionalShadowPartition, FramePacer, FrameStage, GlobalContext, LimitFramerate, Msaa,
-    PARTITION_COUNT, Partition, Prepare, PresentModeInfo, RENDER_TO_TEXTURE_FORMAT, ScreenSpaceAntiAliasing, ShadowResolution, Ssaa,
-    Surface, TextureSamplerType,
+    PARTITION_COUNT, Partition, Prepare, PresentMode, PresentModeInfo, RENDER_TO_TEXTURE_FORMAT, ScreenSpaceAntiAliasing, ShadowBias,
+    ShadowDetail, ShadowResolution, Ssaa, Surface, TextureSamplerType,
 };
 use crate::graphics::ScreenSize;
 use crate::graphics::instruction::RenderInstruction;
@@ -176,9 +176,11 @@ impl GraphicsEngine {
         &mut self,
         window: Arc<Window>,
         triple_buffering: bool,
-        vsync: bool,
+        present_mode: PresentMode,
         limit_framerate: LimitFramerate,
         shadow_resolution: ShadowResolution,
+        shadow_detail: ShadowDetail,
+        shadow_bias: ShadowBias,
         texture_sampler_type: TextureSamplerType,
         msaa: Msaa,
         ssaa: Ssaa,
@@ -198,7 +200,7 @@ impl GraphicsEngine {
                     screen_size.width as u32,
                     screen_size.height as u32,
                     triple_buffering,
-                    vsync,
+                    present_mode,
                 );
 
                 let surface_texture_format = surface.format();
@@ -210,6 +212,7 @@ impl GraphicsEngine {
                     time_phase!("create contexts", {
                         let high_quality_interface = self.check_high_quality_interface_requirements(high_quality_interface, screen_size);
                         let ssaa = self.check_ssaa_requirements(ssaa, screen_size);
+                        let msaa = self.check_msaa_requirements(msaa);
 
                         let global_context = GlobalContext::new(
                             &self.device,
@@ -222,6 +225,8 @@ impl GraphicsEngine {
                             screen_space_anti_aliasing,
                             screen_size,
                             shadow_resolution,
+                            shadow_detail,
+                            shadow_bias,
                             texture_sampler_type,
                             high_quality_interface,
                         );
@@ -537,6 +542,22 @@ impl GraphicsEngine {
         ssaa
     }
 
+    fn check_msaa_requirements(&self, msaa: Msaa) -> Msaa {
+        let supported_msaa = msaa.clamp_to_supported(self.capabilities.get_supported_msaa());
+
+        #[cfg(feature = "debug")]
+        if supported_msaa != msaa {
+            print_debug!(
+                "[{}] multisampling {} is not supported, falling back to {}",
+                "error".red(),
+                msaa,
+                supported_msaa
+            );
+        }
+
+        supported_msaa
+    }
+
     pub fn on_suspended(&mut self) {
         // Android devices are expected to drop their surface view.
         if cfg!(target_os = "android") {
@@ -550,20 +571,20 @@ impl GraphicsEngine {
         }
     }
 
-    pub fn set_vsync(&mut self, enabled: bool) {
+    pub fn set_present_mode(&mut self, present_mode: PresentMode) {
         if let Some(surface) = self.surface.as_mut() {
-            surface.set_vsync(enabled);
+            surface.set_present_mode(present_mode);
         }
     }
 
     pub fn set_limit_framerate(&mut self, limit_framerate: LimitFramerate) {
-        match limit_framerate {
-            LimitFramerate::Unlimited => {
-                self.limit_framerate = false;
-            }
-            LimitFramerate::Limit(rate) => {
+        match limit_framerate.frame_budget() {
+            Some(frame_budget) => {
                 self.limit_framerate = true;
-                self.frame_pacer.set_monitor_frequency(f64::from(rate));
+                self.frame_pacer.set_monitor_frequency(frame_budget.as_secs_f64().recip());
+            }
+            None => {
+                self.limit_framerate = false;
             }
         }
     }
@@ -591,6 +612,8 @@ impl GraphicsEngine {
     }
 
     pub fn set_msaa(&mut self, msaa: Msaa) {
+        let msaa = self.check_msaa_requirements(msaa);
+
         if let Some(engine_context) = self.engine_context.as_mut() {
             engine_context.global_context.update_msaa(&self.device, msaa);
 
@@ -722,11 +745,68 @@ impl GraphicsEngine {
         }
     }
 
-    pub fn set_shadow_resolution(&mut self, shadow_resolution: ShadowResolution) {
+    pub fn set_shadow_resolution(&mut self, shadow_resolution: ShadowResolution, shadow_detail: ShadowDetail) {
         if let Some(engine_context) = self.engine_context.as_mut() {
             engine_context
                 .global_context
-                .update_shadow_size_textures(&self.device, shadow_resolution);
+                .update_shadow_size_textures(&self.device, shadow_resolution, shadow_detail);
+        }
+    }
+
+    pub fn set_shadow_bias(&mut self, shadow_bias: ShadowBias) {
+        if let Some(engine_context) = self.engine_context.as_mut() {
+            engine_context.global_context.update_shadow_bias(shadow_bias);
+
+            // The depth bias is part of the pipeline state, so we need to re-create
+            // all shadow drawers.
+            engine_context.directional_shadow_model_drawer = DirectionalShadowModelDrawer::new(
+                &self.capabilities,
+                &self.device,
+                &self.queue,
+                &self.shader_compiler,
+                &engine_context.global_context,
+                &engine_context.directional_shadow_pass_context,
+            );
+            engine_context.directional_shadow_entity_drawer = DirectionalShadowEntityDrawer::new(
+                &self.capabilities,
+                &self.device,
+                &self.queue,
+                &self.shader_compiler,
+                &engine_context.global_context,
+                &engine_context.directional_shadow_pass_context,
+            );
+            engine_context.directional_shadow_indicator_drawer = DirectionalShadowIndicatorDrawer::new(
+                &self.capabilities,
+                &self.device,
+                &self.queue,
+                &self.shader_compiler,
+                &engine_context.global_context,
+                &engine_context.directional_shadow_pass_context,
+            );
+            engine_context.point_shadow_model_drawer = PointShadowModelDrawer::new(
+                &self.capabilities,
+                &self.device,
+                &self.queue,
+                &self.shader_compiler,
+                &engine_context.global_context,
+                &engine_context.point_shadow_pass_context,
+            );
+            engine_context.point_shadow_entity_drawer = PointShadowEntityDrawer::new(
+                &self.capabilities,
+                &self.device,
+                &self.queue,
+                &self.shader_compiler,
+                &engine_context.global_context,
+                &engine_context.point_shadow_pass_context,
+            );
+            engine_context.point_shadow_indicator_drawer = PointShadowIndicatorDrawer::new(
+                &self.capabilities,
+                &self.device,
+                &self.queue,
+                &self.shader_compiler,
+                &engine_context.global_context,
+                &engine_context.point_shadow_pass_context,
+            );
         }
     }
 
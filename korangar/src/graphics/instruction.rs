@@ -67,6 +67,8 @@ pub struct Uniforms {
     pub shadow_detail: ShadowDetail,
     pub use_sdsm: bool,
     pub sdsm_enabled: bool,
+    pub gamma: f32,
+    pub brightness: f32,
 }
 
 impl Default for Uniforms {
@@ -82,6 +84,8 @@ impl Default for Uniforms {
             shadow_detail: ShadowDetail::Low,
             use_sdsm: false,
             sdsm_enabled: false,
+            gamma: 1.0,
+            brightness: 1.0,
         }
     }
 }
@@ -97,6 +97,9 @@ pub(crate) struct GlobalUniforms {
     shadow_method: u32,
     shadow_detail: u32,
     use_sdsm: u32,
+    gamma: f32,
+    brightness: f32,
+    padding: [u32; 2],
 }
 
 #[derive(Copy, Clone, Pod, Zeroable)]
@@ -362,6 +365,7 @@ pub(crate) struct GlobalContext {
     pub(crate) ssaa: Ssaa,
     pub(crate) screen_space_anti_aliasing: ScreenSpaceAntiAliasing,
     pub(crate) high_quality_interface: bool,
+    pub(crate) shadow_bias: ShadowBias,
     pub(crate) solid_pixel_texture: Arc<Texture>,
     pub(crate) walk_indicator_texture: Arc<Texture>,
     pub(crate) forward_depth_texture: AttachmentTexture,
@@ -476,6 +480,9 @@ impl Prepare for GlobalContext {
             shadow_method: instructions.uniforms.shadow_method.into(),
             shadow_detail: instructions.uniforms.shadow_detail.into(),
             use_sdsm: instructions.uniforms.use_sdsm as u32,
+            gamma: instructions.uniforms.gamma,
+            brightness: instructions.uniforms.brightness,
+            padding: Default::default(),
         };
 
         self.directional_light_uniforms = DirectionalLightUniforms {
@@ -631,13 +638,15 @@ impl GlobalContext {
         screen_space_anti_aliasing: ScreenSpaceAntiAliasing,
         screen_size: ScreenSize,
         shadow_resolution: ShadowResolution,
+        shadow_detail: ShadowDetail,
+        shadow_bias: ShadowBias,
         texture_sampler: TextureSamplerType,
         high_quality_interface: bool,
     ) -> Self {
         let forward_size = ssaa.calculate_size(screen_size);
         let interface_size = if high_quality_interface { screen_size * 2.0 } else { screen_size };
         let directional_shadow_size = ScreenSize::uniform(shadow_resolution.directional_shadow_resolution() as f32);
-        let point_shadow_size = ScreenSize::uniform(shadow_resolution.point_shadow_resolution() as f32);
+        let point_shadow_size = ScreenSize::uniform(shadow_detail.point_shadow_resolution() as f32);
 
         let solid_pixel_texture = Arc::new(Texture::new_with_data(
             device,
@@ -813,6 +822,7 @@ impl GlobalContext {
             ssaa,
             screen_space_anti_aliasing,
             high_quality_interface,
+            shadow_bias,
             solid_pixel_texture,
             walk_indicator_texture,
             forward_depth_texture: forward_textures.forward_depth_texture,
@@ -1122,9 +1132,9 @@ impl GlobalContext {
         }
     }
 
-    fn update_shadow_size_textures(&mut self, device: &Device, shadow_resolution: ShadowResolution) {
+    fn update_shadow_size_textures(&mut self, device: &Device, shadow_resolution: ShadowResolution, shadow_detail: ShadowDetail) {
         self.directional_shadow_size = ScreenSize::uniform(shadow_resolution.directional_shadow_resolution() as f32);
-        self.point_shadow_size = ScreenSize::uniform(shadow_resolution.point_shadow_resolution() as f32);
+        self.point_shadow_size = ScreenSize::uniform(shadow_detail.point_shadow_resolution() as f32);
 
         self.directional_shadow_map_texture = Self::create_directional_shadow_textures(device, self.directional_shadow_size);
         self.directional_shadow_translucence_texture =
@@ -1162,6 +1172,10 @@ impl GlobalContext {
         }
     }
 
+    fn update_shadow_bias(&mut self, shadow_bias: ShadowBias) {
+        self.shadow_bias = shadow_bias;
+    }
+
     fn update_texture_sampler(&mut self, device: &Device, capabilities: &Capabilities, texture_sampler_type: TextureSamplerType) {
         self.texture_sampler = create_new_sampler(device, capabilities, "texture", texture_sampler_type);
         self.global_bind_group = Self::create_global_bind_group(
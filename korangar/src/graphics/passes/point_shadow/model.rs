@@ -46,7 +46,7 @@ impl Drawer<{ BindGroupCount::Two }, { ColorAttachmentCount::None }, { DepthAtta
         device: &Device,
         _queue: &Queue,
         shader_compiler: &ShaderCompiler,
-        _global_context: &GlobalContext,
+        global_context: &GlobalContext,
         render_pass_context: &Self::Context,
     ) -> Self {
         let shader_module = match capabilities.bindless_support() {
@@ -125,7 +125,7 @@ impl Drawer<{ BindGroupCount::Two }, { ColorAttachmentCount::None }, { DepthAtta
                 depth_write_enabled: true,
                 depth_compare: CompareFunction::Greater,
                 stencil: Default::default(),
-                bias: Default::default(),
+                bias: global_context.shadow_bias.depth_bias_state(),
             }),
             cache: None,
         });
@@ -4,7 +4,7 @@ use wgpu::{
 };
 
 use crate::graphics::passes::screen_blit::ScreenBlitRenderPassContext;
-use crate::graphics::passes::{BindGroupCount, ColorAttachmentCount, DepthAttachmentCount, Drawer};
+use crate::graphics::passes::{BindGroupCount, ColorAttachmentCount, DepthAttachmentCount, Drawer, RenderPassContext};
 use crate::graphics::shader_compiler::ShaderCompiler;
 use crate::graphics::{AttachmentTexture, Capabilities, GlobalContext};
 
@@ -14,7 +14,7 @@ pub(crate) struct ScreenBlitBlitterDrawer {
     pipeline: RenderPipeline,
 }
 
-impl Drawer<{ BindGroupCount::None }, { ColorAttachmentCount::One }, { DepthAttachmentCount::None }> for ScreenBlitBlitterDrawer {
+impl Drawer<{ BindGroupCount::One }, { ColorAttachmentCount::One }, { DepthAttachmentCount::None }> for ScreenBlitBlitterDrawer {
     type Context = ScreenBlitRenderPassContext;
     type DrawData<'data> = &'data AttachmentTexture;
 
@@ -44,7 +44,7 @@ impl Drawer<{ BindGroupCount::None }, { ColorAttachmentCount::One }, { DepthAtta
 
         let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
             label: Some(&label),
-            bind_group_layouts: &[&texture_bind_group_layout],
+            bind_group_layouts: &[Self::Context::bind_group_layout(device)[0], &texture_bind_group_layout],
             push_constant_ranges: &[],
         });
 
@@ -79,7 +79,7 @@ impl Drawer<{ BindGroupCount::None }, { ColorAttachmentCount::One }, { DepthAtta
 
     fn draw(&mut self, pass: &mut RenderPass<'_>, draw_data: Self::DrawData<'_>) {
         pass.set_pipeline(&self.pipeline);
-        pass.set_bind_group(0, draw_data.get_bind_group(), &[]);
+        pass.set_bind_group(1, draw_data.get_bind_group(), &[]);
         pass.draw(0..3, 0..1);
     }
 }
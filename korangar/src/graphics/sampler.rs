@@ -1,3 +1,5 @@
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
 use wgpu::{AddressMode, CompareFunction, Device, FilterMode, Sampler, SamplerBorderColor, SamplerDescriptor};
 
 use crate::graphics::{Capabilities, TextureSamplerType};
@@ -48,7 +50,7 @@ pub(crate) fn create_new_sampler(
             lod_min_clamp: 0.0,
             lod_max_clamp: 32.0,
             compare: None,
-            anisotropy_clamp,
+            anisotropy_clamp: clamp_anisotropy(anisotropy_clamp, capabilities.get_max_sampler_anisotropy()),
             border_color: None,
         }),
         SamplerType::DepthCompare => {
@@ -78,6 +80,23 @@ pub(crate) fn create_new_sampler(
     }
 }
 
+/// Clamps the requested anisotropy to the range supported by the device.
+/// Values outside of that range are rejected when creating the sampler.
+fn clamp_anisotropy(anisotropy: u16, max_anisotropy: u16) -> u16 {
+    let clamped = anisotropy.clamp(1, max_anisotropy);
+
+    #[cfg(feature = "debug")]
+    if clamped != anisotropy {
+        print_debug!(
+            "requested anisotropy of {} is not supported, using {} instead",
+            anisotropy.yellow(),
+            clamped.green()
+        );
+    }
+
+    clamped
+}
+
 pub(crate) enum SamplerType {
     TextureNearest,
     TextureLinear,
@@ -94,3 +113,16 @@ impl From<TextureSamplerType> for SamplerType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::clamp_anisotropy;
+    use crate::graphics::MAX_SAMPLER_ANISOTROPY;
+
+    #[test]
+    fn anisotropy_clamped_to_device_max() {
+        assert_eq!(clamp_anisotropy(64, MAX_SAMPLER_ANISOTROPY), MAX_SAMPLER_ANISOTROPY);
+        assert_eq!(clamp_anisotropy(8, MAX_SAMPLER_ANISOTROPY), 8);
+        assert_eq!(clamp_anisotropy(0, MAX_SAMPLER_ANISOTROPY), 1);
+    }
+}
@@ -1,12 +1,14 @@
 use std::fmt::{Display, Formatter};
 #[cfg(feature = "debug")]
 use std::num::NonZeroU32;
+use std::time::Duration;
 
 use korangar_interface::components::drop_down::DropDownItem;
 use korangar_interface::element::StateElement;
 use serde::{Deserialize, Serialize};
+use wgpu::DepthBiasState;
 
-use crate::graphics::ScreenSize;
+use crate::graphics::{PARTITION_COUNT, ScreenSize};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, StateElement)]
 pub enum LimitFramerate {
@@ -14,6 +16,17 @@ pub enum LimitFramerate {
     Limit(u16),
 }
 
+impl LimitFramerate {
+    /// The time available to render a single frame. [`None`] if the framerate
+    /// is not limited.
+    pub fn frame_budget(self) -> Option<Duration> {
+        match self {
+            LimitFramerate::Unlimited | LimitFramerate::Limit(0) => None,
+            LimitFramerate::Limit(rate) => Some(Duration::from_secs(1) / u32::from(rate)),
+        }
+    }
+}
+
 impl DropDownItem<LimitFramerate> for LimitFramerate {
     fn text(&self) -> &str {
         match self {
@@ -32,6 +45,47 @@ impl DropDownItem<LimitFramerate> for LimitFramerate {
     }
 }
 
+/// The way finished frames are presented to the screen.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, StateElement)]
+pub enum PresentMode {
+    /// Vsync off. Frames are presented immediately, which may cause tearing.
+    Immediate,
+    /// Vsync on (fast). Frames are presented on the next vertical blank, but
+    /// newer frames replace queued ones.
+    Mailbox,
+    /// Vsync on. Frames are presented on the next vertical blank.
+    #[default]
+    Fifo,
+    /// Vsync on, but late frames are presented immediately, which may cause
+    /// tearing.
+    FifoRelaxed,
+}
+
+impl PresentMode {
+    /// Maps a legacy vsync setting to a present mode.
+    pub fn from_vsync(vsync: bool) -> Self {
+        match vsync {
+            true => PresentMode::Fifo,
+            false => PresentMode::Mailbox,
+        }
+    }
+}
+
+impl DropDownItem<PresentMode> for PresentMode {
+    fn text(&self) -> &str {
+        match self {
+            PresentMode::Immediate => "Off",
+            PresentMode::Mailbox => "Fast",
+            PresentMode::Fifo => "On",
+            PresentMode::FifoRelaxed => "Adaptive",
+        }
+    }
+
+    fn value(&self) -> PresentMode {
+        *self
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, StateElement)]
 pub enum TextureSamplerType {
     Nearest,
@@ -71,14 +125,6 @@ impl ShadowResolution {
             ShadowResolution::Insane => 4096,
         }
     }
-
-    pub fn point_shadow_resolution(self) -> u32 {
-        match self {
-            ShadowResolution::Normal => 128,
-            ShadowResolution::Ultra => 256,
-            ShadowResolution::Insane => 512,
-        }
-    }
 }
 
 impl DropDownItem<ShadowResolution> for ShadowResolution {
@@ -95,6 +141,44 @@ impl DropDownItem<ShadowResolution> for ShadowResolution {
     }
 }
 
+/// Maximum distance at which objects are still rendered.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, StateElement)]
+pub enum ViewDistance {
+    Near,
+    Medium,
+    Far,
+    #[default]
+    Unlimited,
+}
+
+impl ViewDistance {
+    /// The view distance in map tiles, measured from the camera. [`None`]
+    /// if the view distance is unlimited.
+    pub fn tiles(self) -> Option<u32> {
+        match self {
+            ViewDistance::Near => Some(150),
+            ViewDistance::Medium => Some(250),
+            ViewDistance::Far => Some(400),
+            ViewDistance::Unlimited => None,
+        }
+    }
+}
+
+impl DropDownItem<ViewDistance> for ViewDistance {
+    fn text(&self) -> &str {
+        match self {
+            Self::Near => "Near",
+            Self::Medium => "Medium",
+            Self::Far => "Far",
+            Self::Unlimited => "Unlimited",
+        }
+    }
+
+    fn value(&self) -> ViewDistance {
+        *self
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, StateElement)]
 pub enum ShadowDetail {
     Low,
@@ -118,6 +202,57 @@ impl DropDownItem<ShadowDetail> for ShadowDetail {
     }
 }
 
+impl ShadowDetail {
+    /// The default depth bias used when rendering shadow casters at this
+    /// level of detail.
+    pub fn shadow_bias(self) -> ShadowBias {
+        match self {
+            ShadowDetail::Low => ShadowBias {
+                constant: 2,
+                slope_scale: 2.0,
+            },
+            ShadowDetail::Medium => ShadowBias {
+                constant: 1,
+                slope_scale: 1.5,
+            },
+            ShadowDetail::High => ShadowBias {
+                constant: 1,
+                slope_scale: 1.0,
+            },
+            ShadowDetail::Ultra => ShadowBias {
+                constant: 0,
+                slope_scale: 1.0,
+            },
+        }
+    }
+
+    /// Number of cascades used for directional shadows when the partitions
+    /// are computed with PSSM. SDSM always uses all partitions.
+    pub fn cascade_count(self) -> usize {
+        match self {
+            ShadowDetail::Low => 1,
+            ShadowDetail::Medium => 2,
+            ShadowDetail::High | ShadowDetail::Ultra => PARTITION_COUNT,
+        }
+    }
+
+    /// Whether point lights cast shadows at this level of detail. If they
+    /// don't, all point lights are rendered without shadow maps.
+    pub fn enable_point_shadows(self) -> bool {
+        self != ShadowDetail::Low
+    }
+
+    /// Size of a single face of the point shadow cube maps.
+    pub fn point_shadow_resolution(self) -> u32 {
+        match self {
+            ShadowDetail::Low => 64,
+            ShadowDetail::Medium => 128,
+            ShadowDetail::High => 256,
+            ShadowDetail::Ultra => 512,
+        }
+    }
+}
+
 impl From<ShadowDetail> for u32 {
     fn from(value: ShadowDetail) -> Self {
         match value {
@@ -129,6 +264,35 @@ impl From<ShadowDetail> for u32 {
     }
 }
 
+/// Depth bias applied to shadow casters when rendering the shadow maps.
+/// Larger values push the casters further away from the light, which reduces
+/// shadow acne but can detach shadows from their casters (peter-panning).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, rust_state::RustState, StateElement)]
+pub struct ShadowBias {
+    /// Constant bias in units of the smallest resolvable depth difference.
+    pub constant: i32,
+    /// Bias that scales with the slope of the rendered surface.
+    pub slope_scale: f32,
+}
+
+impl Default for ShadowBias {
+    fn default() -> Self {
+        ShadowDetail::Medium.shadow_bias()
+    }
+}
+
+impl ShadowBias {
+    pub fn depth_bias_state(self) -> DepthBiasState {
+        // We use a reversed depth buffer, so moving away from the light means
+        // lowering the depth value.
+        DepthBiasState {
+            constant: -self.constant,
+            slope_scale: -self.slope_scale,
+            clamp: 0.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, StateElement)]
 pub enum ShadowMethod {
     Hard,
@@ -197,15 +361,22 @@ impl Display for Msaa {
     }
 }
 
-impl From<u32> for Msaa {
-    fn from(value: u32) -> Self {
+/// Error when converting a sample count that doesn't match any [`Msaa`]
+/// level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownSampleCount(pub u32);
+
+impl TryFrom<u32> for Msaa {
+    type Error = UnknownSampleCount;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
         match value {
-            1 => Msaa::Off,
-            2 => Msaa::X2,
-            4 => Msaa::X4,
-            8 => Msaa::X8,
-            16 => Msaa::X16,
-            _ => panic!("Unknown sample count"),
+            1 => Ok(Msaa::Off),
+            2 => Ok(Msaa::X2),
+            4 => Ok(Msaa::X4),
+            8 => Ok(Msaa::X8),
+            16 => Ok(Msaa::X16),
+            _ => Err(UnknownSampleCount(value)),
         }
     }
 }
@@ -224,6 +395,17 @@ impl Msaa {
     pub fn multisampling_activated(self) -> bool {
         self != Msaa::Off
     }
+
+    /// The highest level in `supported` that is not above `self`. Falls back
+    /// to [`Msaa::Off`] if there is none.
+    pub fn clamp_to_supported(self, supported: &[Msaa]) -> Msaa {
+        supported
+            .iter()
+            .copied()
+            .filter(|msaa| msaa.sample_count() <= self.sample_count())
+            .max_by_key(|msaa| msaa.sample_count())
+            .unwrap_or(Msaa::Off)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -397,3 +579,48 @@ impl RenderOptions {
             || self.show_font_map
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{LimitFramerate, Msaa, ShadowDetail, UnknownSampleCount};
+
+    #[test]
+    fn frame_budget() {
+        let frame_budget = LimitFramerate::Limit(60).frame_budget().unwrap();
+
+        assert!(frame_budget.abs_diff(Duration::from_micros(16_667)) < Duration::from_micros(1));
+        assert_eq!(LimitFramerate::Unlimited.frame_budget(), None);
+        assert_eq!(LimitFramerate::Limit(0).frame_budget(), None);
+    }
+
+    #[test]
+    fn point_shadows() {
+        assert!(!ShadowDetail::Low.enable_point_shadows());
+        assert!(ShadowDetail::Medium.enable_point_shadows());
+        assert!(ShadowDetail::High.enable_point_shadows());
+        assert!(ShadowDetail::Ultra.enable_point_shadows());
+        assert_eq!(ShadowDetail::Low.point_shadow_resolution(), 64);
+        assert_eq!(ShadowDetail::Ultra.point_shadow_resolution(), 512);
+    }
+
+    #[test]
+    fn clamp_msaa() {
+        let supported = [Msaa::Off, Msaa::X2, Msaa::X4];
+        assert_eq!(Msaa::X16.clamp_to_supported(&supported), Msaa::X4);
+        assert_eq!(Msaa::X2.clamp_to_supported(&supported), Msaa::X2);
+        assert_eq!(Msaa::X8.clamp_to_supported(&[Msaa::Off]), Msaa::Off);
+
+        // Lower levels are not implied by higher ones being supported.
+        let supported = [Msaa::Off, Msaa::X4, Msaa::X16];
+        assert_eq!(Msaa::X8.clamp_to_supported(&supported), Msaa::X4);
+        assert_eq!(Msaa::X2.clamp_to_supported(&supported), Msaa::Off);
+    }
+
+    #[test]
+    fn msaa_from_sample_count() {
+        assert_eq!(Msaa::try_from(4), Ok(Msaa::X4));
+        assert_eq!(Msaa::try_from(3), Err(UnknownSampleCount(3)));
+    }
+}
@@ -2,9 +2,9 @@ use cgmath::Vector2;
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, print_debug};
 use rust_state::RustState;
-use wgpu::{Adapter, Device, PresentMode, SurfaceConfiguration, SurfaceTexture, TextureFormat};
+use wgpu::{Adapter, Device, SurfaceConfiguration, SurfaceTexture, TextureFormat};
 
-use crate::graphics::ScreenSize;
+use crate::graphics::{PresentMode, ScreenSize};
 
 #[derive(RustState, Debug, Clone, Copy)]
 pub struct PresentModeInfo {
@@ -12,6 +12,8 @@ pub struct PresentModeInfo {
     pub supports_mailbox: bool,
     /// Vsync Off
     pub supports_immediate: bool,
+    /// Vsync On (Adaptive)
+    pub supports_fifo_relaxed: bool,
 }
 
 impl PresentModeInfo {
@@ -19,6 +21,7 @@ impl PresentModeInfo {
         let mut present_mode_info = PresentModeInfo {
             supports_immediate: false,
             supports_mailbox: false,
+            supports_fifo_relaxed: false,
         };
 
         surface
@@ -26,13 +29,48 @@ impl PresentModeInfo {
             .present_modes
             .iter()
             .for_each(|present_mode| match present_mode {
-                PresentMode::Mailbox => present_mode_info.supports_mailbox = true,
-                PresentMode::Immediate => present_mode_info.supports_immediate = true,
+                wgpu::PresentMode::Mailbox => present_mode_info.supports_mailbox = true,
+                wgpu::PresentMode::Immediate => present_mode_info.supports_immediate = true,
+                wgpu::PresentMode::FifoRelaxed => present_mode_info.supports_fifo_relaxed = true,
                 _ => {}
             });
 
         present_mode_info
     }
+
+    /// Get the present mode to configure the surface with. Falls back to a
+    /// similar supported mode if the requested one is not supported. FIFO is
+    /// guaranteed to be supported.
+    pub fn resolve(&self, present_mode: PresentMode) -> wgpu::PresentMode {
+        match present_mode {
+            PresentMode::Immediate if self.supports_immediate => wgpu::PresentMode::Immediate,
+            PresentMode::Immediate | PresentMode::Mailbox if self.supports_mailbox => wgpu::PresentMode::Mailbox,
+            PresentMode::Mailbox if self.supports_immediate => wgpu::PresentMode::Immediate,
+            PresentMode::FifoRelaxed if self.supports_fifo_relaxed => wgpu::PresentMode::FifoRelaxed,
+            _ => wgpu::PresentMode::Fifo,
+        }
+    }
+
+    /// All present modes that can be selected on this surface.
+    pub fn supported_present_modes(&self) -> Vec<PresentMode> {
+        let mut present_modes = Vec::new();
+
+        if self.supports_immediate {
+            present_modes.push(PresentMode::Immediate);
+        }
+
+        if self.supports_mailbox {
+            present_modes.push(PresentMode::Mailbox);
+        }
+
+        present_modes.push(PresentMode::Fifo);
+
+        if self.supports_fifo_relaxed {
+            present_modes.push(PresentMode::FifoRelaxed);
+        }
+
+        present_modes
+    }
 }
 
 pub struct Surface {
@@ -51,7 +89,7 @@ impl Surface {
         window_width: u32,
         window_height: u32,
         triple_buffering: bool,
-        vsync: bool,
+        present_mode: PresentMode,
     ) -> Self {
         let window_width = window_width.max(1);
         let window_height = window_height.max(1);
@@ -75,11 +113,7 @@ impl Surface {
             true => 2,
             false => 1,
         };
-        config.present_mode = match vsync {
-            false if present_mode_info.supports_mailbox => PresentMode::Mailbox,
-            false if present_mode_info.supports_immediate => PresentMode::Immediate,
-            _ => PresentMode::Fifo,
-        };
+        config.present_mode = present_mode_info.resolve(present_mode);
 
         #[cfg(feature = "debug")]
         {
@@ -142,12 +176,8 @@ impl Surface {
         self.surface.configure(&self.device, &self.config);
     }
 
-    pub fn set_vsync(&mut self, enabled: bool) {
-        self.config.present_mode = match enabled {
-            false if self.present_mode_info.supports_mailbox => PresentMode::Mailbox,
-            false if self.present_mode_info.supports_immediate => PresentMode::Immediate,
-            _ => PresentMode::Fifo,
-        };
+    pub fn set_present_mode(&mut self, present_mode: PresentMode) {
+        self.config.present_mode = self.present_mode_info.resolve(present_mode);
 
         #[cfg(feature = "debug")]
         print_debug!("set surface present mode to {:?}", self.config.present_mode.magenta());
@@ -1,2 +1,3 @@
 pub mod item_box;
+pub mod mutable_range;
 pub mod skill_box;
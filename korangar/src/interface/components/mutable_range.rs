@@ -0,0 +1,65 @@
+use std::ops::RangeInclusive;
+
+use korangar_interface::element::Element;
+use korangar_interface::event::{ClickHandler, EventQueue};
+use rust_state::{Context, Path};
+
+use crate::loaders::OverflowBehavior;
+use crate::state::ClientState;
+
+/// Moves a value by a fixed step while keeping it inside of its range.
+struct StepHandler<P> {
+    value_path: P,
+    step: f32,
+    range: RangeInclusive<f32>,
+}
+
+impl<P> StepHandler<P> {
+    fn new(value_path: P, step: f32, range: RangeInclusive<f32>) -> Self {
+        Self { value_path, step, range }
+    }
+}
+
+impl<P> ClickHandler<ClientState> for StepHandler<P>
+where
+    P: Path<ClientState, f32>,
+{
+    fn handle_click(&self, state: &Context<ClientState>, _: &mut EventQueue<ClientState>) {
+        let step = self.step;
+        let minimum = *self.range.start();
+        let maximum = *self.range.end();
+
+        state.update_value_with(self.value_path, move |value| {
+            *value = (*value + step).clamp(minimum, maximum);
+        });
+    }
+}
+
+/// Labeled control that changes a value inside of a range in fixed steps.
+pub fn mutable_range<P>(label: &'static str, value_path: P, range: RangeInclusive<f32>, step: f32) -> impl Element<ClientState>
+where
+    P: Path<ClientState, f32>,
+{
+    use korangar_interface::prelude::*;
+
+    split! {
+        gaps: theme().window().gaps(),
+        children: (
+            text! {
+                text: label,
+                overflow_behavior: OverflowBehavior::Shrink,
+            },
+            button! {
+                text: "-",
+                event: StepHandler::new(value_path, -step, range.clone()),
+            },
+            field! {
+                text: ElementDisplaySelector::new(value_path),
+            },
+            button! {
+                text: "+",
+                event: StepHandler::new(value_path, step, range),
+            },
+        ),
+    }
+}
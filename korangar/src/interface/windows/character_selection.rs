@@ -318,10 +318,7 @@ mod character_slot_preview {
                 layout.add_text(
                     layout_info.area,
                     // TODO: Replace with a map name lookup
-                    character_information
-                        .map_name
-                        .strip_suffix(".gat")
-                        .unwrap_or(&character_information.map_name),
+                    character_information.normalized_map_name(),
                     FontSize(14.0),
                     Color::rgb_u8(200, 200, 150),
                     Color::rgb_u8(255, 160, 60),
@@ -1,9 +1,10 @@
 use korangar_interface::window::{CustomWindow, Window};
 use rust_state::Path;
 
+use crate::interface::components::mutable_range::mutable_range;
 use crate::interface::windows::WindowClass;
 use crate::loaders::OverflowBehavior;
-use crate::settings::{GraphicsSettingsCapabilitiesPathExt, GraphicsSettingsPathExt};
+use crate::settings::{BRIGHTNESS_RANGE, FIELD_OF_VIEW_RANGE, GAMMA_RANGE, GraphicsSettingsCapabilitiesPathExt, GraphicsSettingsPathExt};
 use crate::state::ClientState;
 use crate::state::theme::InterfaceThemeType;
 use crate::{GraphicsSettings, GraphicsSettingsCapabilities};
@@ -52,12 +53,17 @@ where
                 state: self.settings_path.triple_buffering(),
                 event: Toggle(self.settings_path.triple_buffering()),
             },
-            state_button! {
-                text: "Enable VSYNC",
-                state: self.settings_path.vsync(),
-                event: Toggle(self.settings_path.vsync()),
-                disabled: self.capabilities_path.vsync_setting_disabled(),
-                disabled_tooltip: "This setting is not supported on your system",
+            split! {
+                children: (
+                    text! {
+                        text: "VSYNC",
+                        overflow_behavior: OverflowBehavior::Shrink,
+                    },
+                    drop_down! {
+                        selected: self.settings_path.present_mode(),
+                        options: self.capabilities_path.present_mode_options(),
+                    }
+                )
             },
             split! {
                 children: (
@@ -155,6 +161,21 @@ where
                     }
                 )
             },
+            split! {
+                children: (
+                    text! {
+                        text: "View distance",
+                        overflow_behavior: OverflowBehavior::Shrink,
+                    },
+                    drop_down! {
+                        selected: self.settings_path.view_distance(),
+                        options: self.capabilities_path.view_distance_options(),
+                    }
+                )
+            },
+            mutable_range("Field of view", self.settings_path.field_of_view(), FIELD_OF_VIEW_RANGE, 1.0),
+            mutable_range("Gamma", self.settings_path.gamma(), GAMMA_RANGE, 0.1),
+            mutable_range("Brightness", self.settings_path.brightness(), BRIGHTNESS_RANGE, 0.1),
             state_button! {
                 text: "Sample Distribution Shadow Maps",
                 state: self.settings_path.sdsm(),
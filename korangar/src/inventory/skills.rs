@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use korangar_interface::element::StateElement;
-use ragnarok_packets::{ClientTick, SkillId, SkillInformation, SkillLevel, SkillType};
+use ragnarok_packets::{ClientTick, SkillId, SkillInformation, SkillLevel, SkillTypeFlags};
 use rust_state::RustState;
 
 use crate::loaders::{ActionLoader, Sprite, SpriteLoader};
@@ -11,7 +11,7 @@ use crate::world::{Actions, SpriteAnimationState};
 pub struct Skill {
     pub skill_id: SkillId,
     pub skill_level: SkillLevel,
-    pub skill_type: SkillType,
+    pub skill_type: SkillTypeFlags,
     pub skill_name: String,
     // TODO: Unhide this
     #[hidden_element]
@@ -21,7 +21,7 @@ use self::vertices::{generate_tile_vertices, ground_vertices};
 use self::water_plane::generate_water_plane;
 use super::error::LoadError;
 use crate::graphics::{BindlessSupport, Buffer, ModelVertex, TextureSet};
-use crate::loaders::{GameFileLoader, ModelLoader, TextureLoader, TextureSetBuilder, VideoLoader, split_mesh_by_texture};
+use crate::loaders::{GameFileLoader, ModelLoader, TextureLoader, TextureSetBuilder, VideoLoader, WindingOrder, split_mesh_by_texture};
 use crate::world::{Library, LightSourceKey, Lighting, MapSkyData, Model, SubMesh, Video};
 use crate::{EffectSourceExt, LightSourceExt, Map, Object, ObjectKey, SoundSourceExt};
 
@@ -193,7 +193,7 @@ impl MapLoader {
 
         apply_map_offset(&ground_data, &mut map_data.resources);
 
-        let mut model_cache = HashMap::<(String, bool), Arc<Model>>::new();
+        let mut model_cache = HashMap::<(String, WindingOrder), Arc<Model>>::new();
         let mut objects = SimpleSlab::with_capacity(map_data.resources.objects.len() as u32);
 
         let object_bounding_boxes: Vec<(ObjectKey, AABB)> = map_data
@@ -203,9 +203,10 @@ impl MapLoader {
             .map(|object_data| {
                 let array: [f32; 3] = object_data.transform.scale.into();
                 let reverse_order = array.into_iter().fold(1.0, |a, b| a * b).is_sign_negative();
+                let winding_order = WindingOrder::Clockwise.reversed_if(reverse_order);
 
                 let model = model_cache
-                    .entry((object_data.model_name.clone(), reverse_order))
+                    .entry((object_data.model_name.clone(), winding_order))
                     .or_insert_with(|| {
                         Arc::new(
                             model_loader
@@ -214,7 +215,9 @@ impl MapLoader {
                                     &mut model_vertices,
                                     &mut model_indices,
                                     object_data.model_name.as_str(),
-                                    reverse_order,
+                                    winding_order,
+                                    false,
+                                    None,
                                 )
                                 .expect("can't find model"),
                         )
@@ -18,16 +18,55 @@ use crate::graphics::{BindlessSupport, Color, ModelVertex, NativeModelVertex, re
 use crate::loaders::GameFileLoader;
 use crate::world::{Model, Node, SubMesh};
 
+/// Parts of texture names used for grass, bushes and trees. Only faces with
+/// one of these textures are affected by wind, everything else keeps a wind
+/// affinity of `0.0`. Most texture names of the original client are Korean,
+/// so both the English and the Korean words are checked.
+const WIND_AFFECTED_TEXTURE_NAMES: &[&str] = &["grass", "tree", "leaf", "leaves", "bush", "풀", "나무", "잎", "덤불"];
+
+/// Winding order of the triangles of a model, relative to their normals.
+/// [`WindingOrder::Clockwise`] keeps the vertices in the order of the model
+/// file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindingOrder {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl WindingOrder {
+    /// The opposite winding order.
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Clockwise => Self::CounterClockwise,
+            Self::CounterClockwise => Self::Clockwise,
+        }
+    }
+
+    /// The opposite winding order if `reverse` is `true`, otherwise the same
+    /// winding order.
+    pub fn reversed_if(self, reverse: bool) -> Self {
+        match reverse {
+            true => self.reversed(),
+            false => self,
+        }
+    }
+}
+
 pub struct ModelLoader {
     game_file_loader: Arc<GameFileLoader>,
     bindless_support: BindlessSupport,
+    wind_affinity_threshold: f32,
 }
 
 impl ModelLoader {
-    pub fn new(game_file_loader: Arc<GameFileLoader>, bindless_support: BindlessSupport) -> Self {
+    /// `wind_affinity_threshold` is the relative height (from `0.0` at the
+    /// bottom to `1.0` at the top of a node) above which vertices are affected
+    /// by wind.
+    pub fn new(game_file_loader: Arc<GameFileLoader>, bindless_support: BindlessSupport, wind_affinity_threshold: f32) -> Self {
         Self {
             game_file_loader,
             bindless_support,
+            wind_affinity_threshold: wind_affinity_threshold.clamp(0.0, 1.0),
         }
     }
 }
@@ -38,8 +77,9 @@ impl ModelLoader {
         vertex_positions: &[Point3<f32>],
         texture_coordinates: &[Vector2<f32>],
         smoothing_groups: &SmallVec<[i32; 3]>,
+        wind_affinities: &[f32],
         texture_index: i32,
-        reverse_vertices: bool,
+        winding_order: WindingOrder,
         reverse_normal: bool,
     ) {
         let normal = match reverse_normal {
@@ -50,10 +90,11 @@ impl ModelLoader {
         // If there are degenerated triangles, we at least set a valid normal value.
         let normal = normal.unwrap_or_else(Vector3::unit_y);
 
-        if reverse_vertices {
-            for ((vertex_position, texture_coordinates), target) in vertex_positions
+        if winding_order == WindingOrder::CounterClockwise {
+            for (((vertex_position, texture_coordinates), wind_affinity), target) in vertex_positions
                 .iter()
                 .zip(texture_coordinates.iter())
+                .zip(wind_affinities.iter())
                 .rev()
                 .zip(vertices.iter_mut())
             {
@@ -63,13 +104,16 @@ impl ModelLoader {
                     *texture_coordinates,
                     texture_index,
                     Color::WHITE,
-                    0.0, // TODO: actually add wind affinity
+                    *wind_affinity,
                     smoothing_groups.clone(),
                 );
             }
         } else {
-            for ((vertex_position, texture_coordinates), target) in
-                vertex_positions.iter().zip(texture_coordinates.iter()).zip(vertices.iter_mut())
+            for (((vertex_position, texture_coordinates), wind_affinity), target) in vertex_positions
+                .iter()
+                .zip(texture_coordinates.iter())
+                .zip(wind_affinities.iter())
+                .zip(vertices.iter_mut())
             {
                 *target = NativeModelVertex::new(
                     *vertex_position,
@@ -77,14 +121,44 @@ impl ModelLoader {
                     *texture_coordinates,
                     texture_index,
                     Color::WHITE,
-                    0.0, // TODO: actually add wind affinity
+                    *wind_affinity,
                     smoothing_groups.clone(),
                 );
             }
         }
     }
 
-    fn make_vertices(node: &NodeData, main_matrix: &Matrix4<f32>, reverse_order: bool, smooth_normals: bool) -> Vec<NativeModelVertex> {
+    /// Calculate how much a vertex is affected by wind, based on its height
+    /// inside of the node. Vertices below the threshold don't move at all,
+    /// above it the affinity grows linearly up to `1.0` at the top of the
+    /// node.
+    fn calculate_wind_affinity(position: Point3<f32>, bounding_box: &AABB, wind_affinity_threshold: f32) -> f32 {
+        // The Y axis of models points downwards, so the top of the node has the
+        // smallest value.
+        let height = bounding_box.max().y - bounding_box.min().y;
+
+        if height <= f32::EPSILON || wind_affinity_threshold >= 1.0 {
+            return 0.0;
+        }
+
+        let relative_height = (bounding_box.max().y - position.y) / height;
+        ((relative_height - wind_affinity_threshold) / (1.0 - wind_affinity_threshold)).clamp(0.0, 1.0)
+    }
+
+    /// Check if faces with the given texture are affected by wind.
+    fn is_affected_by_wind(texture_name: &str) -> bool {
+        let texture_name = texture_name.to_lowercase();
+        WIND_AFFECTED_TEXTURE_NAMES.iter().any(|name| texture_name.contains(name))
+    }
+
+    fn make_vertices(
+        node: &NodeData,
+        main_matrix: &Matrix4<f32>,
+        winding_order: WindingOrder,
+        smooth_normals: bool,
+        wind_affected_textures: &[bool],
+        wind_affinity_threshold: f32,
+    ) -> Vec<NativeModelVertex> {
         let face_count = node.faces.len();
         let face_vertex_count = face_count * 3;
         let two_sided_face_count = node.faces.iter().filter(|face| face.two_sided != 0).count();
@@ -95,12 +169,17 @@ impl ModelLoader {
         let mut face_index = 0;
         let mut back_face_index = face_vertex_count;
 
+        // A negative scale mirrors the node, which flips the winding order of all
+        // of its faces. We reverse the vertices to get the original winding back.
         let array: [f32; 3] = node.scale.unwrap_or(Vector3::new(1.0, 1.0, 1.0)).into();
         let reverse_node_order = array.into_iter().fold(1.0, |a, b| a * b).is_sign_negative();
+        let winding_order = winding_order.reversed_if(reverse_node_order);
 
-        if reverse_node_order {
-            panic!("this can actually happen");
-        }
+        let bounding_box = AABB::from_vertices(
+            node.vertex_positions
+                .iter()
+                .map(|position| multiply_matrix4_and_point3(main_matrix, *position)),
+        );
 
         for face in &node.faces {
             let vertex_positions: [Point3<f32>; 3] = std::array::from_fn(|index| {
@@ -118,13 +197,21 @@ impl ModelLoader {
                 std::iter::once(face.smooth_group).chain(face.smooth_group_extra.as_ref().iter().flat_map(|extra| extra.iter().copied())),
             );
 
+            let wind_affinities: [f32; 3] = match wind_affected_textures.get(face.texture_index as usize) {
+                Some(true) => std::array::from_fn(|index| {
+                    Self::calculate_wind_affinity(vertex_positions[index], &bounding_box, wind_affinity_threshold)
+                }),
+                _ => [0.0; 3],
+            };
+
             Self::add_vertices(
                 &mut vertices[face_index..face_index + 3],
                 &vertex_positions,
                 &texture_coordinates,
                 &smoothing_groups,
+                &wind_affinities,
                 face.texture_index as i32,
-                reverse_order,
+                winding_order,
                 false,
             );
             face_index += 3;
@@ -135,8 +222,9 @@ impl ModelLoader {
                     &vertex_positions,
                     &texture_coordinates,
                     &smoothing_groups,
+                    &wind_affinities,
                     face.texture_index as i32,
-                    !reverse_order,
+                    winding_order.reversed(),
                     true,
                 );
                 back_face_index += 3;
@@ -197,12 +285,15 @@ impl ModelLoader {
         model_vertices: &mut Vec<ModelVertex>,
         model_indices: &mut Vec<u32>,
         texture_mapping: &TextureMapping,
+        texture_names: &[String],
         parent_matrix: &Matrix4<f32>,
         main_bounding_box: &mut AABB,
-        reverse_order: bool,
+        winding_order: WindingOrder,
         smooth_normals: bool,
+        wind_affinity_threshold: f32,
         frames_per_second: f32,
         animation_length: u32,
+        load_progress: &mut LoadProgress<'_>,
     ) -> Node {
         let (main_matrix, transform_matrix, box_transform_matrix) = match version.equals_or_above(2, 2) {
             false => Self::calculate_matrices_rsm1(current_node, parent_matrix),
@@ -242,12 +333,15 @@ impl ModelLoader {
                     model_vertices,
                     model_indices,
                     texture_mapping,
+                    texture_names,
                     &box_transform_matrix,
                     main_bounding_box,
-                    reverse_order,
+                    winding_order,
                     smooth_normals,
+                    wind_affinity_threshold,
                     frames_per_second,
                     animation_length,
+                    load_progress,
                 )
             })
             .collect();
@@ -265,7 +359,31 @@ impl ModelLoader {
                 .collect(),
         };
 
-        let node_native_vertices = Self::make_vertices(current_node, &main_matrix, reverse_order, smooth_normals);
+        let wind_affected_textures: Vec<bool> = match version.equals_or_above(2, 3) {
+            false => current_node
+                .texture_indices
+                .iter()
+                .map(|&index| {
+                    texture_names
+                        .get(index as usize)
+                        .is_some_and(|name| Self::is_affected_by_wind(name))
+                })
+                .collect(),
+            true => current_node
+                .texture_names
+                .iter()
+                .map(|name| Self::is_affected_by_wind(name.as_ref()))
+                .collect(),
+        };
+
+        let node_native_vertices = Self::make_vertices(
+            current_node,
+            &main_matrix,
+            winding_order,
+            smooth_normals,
+            &wind_affected_textures,
+            wind_affinity_threshold,
+        );
 
         let centroid = Self::calculate_centroid(&node_native_vertices);
 
@@ -311,7 +429,7 @@ impl ModelLoader {
             false => current_node.rotation_keyframes.clone(),
         };
 
-        match bindless_support {
+        let node = match bindless_support {
             BindlessSupport::Full | BindlessSupport::Limited => {
                 // Remember the index offset, index count, base vertex and gather node vertices.
                 let index_offset = model_indices.len() as u32;
@@ -327,6 +445,7 @@ impl ModelLoader {
                     Matrix4::identity(),
                     position,
                     centroid,
+                    bounding_box,
                     vec![SubMesh {
                         index_offset,
                         index_count,
@@ -362,6 +481,7 @@ impl ModelLoader {
                     Matrix4::identity(),
                     position,
                     centroid,
+                    bounding_box,
                     submeshes,
                     child_nodes,
                     animation_length,
@@ -370,7 +490,11 @@ impl ModelLoader {
                     rotation_keyframes,
                 )
             }
-        }
+        };
+
+        load_progress.node_processed();
+
+        node
     }
 
     pub fn calculate_transformation_matrix(
@@ -453,72 +577,79 @@ impl ModelLoader {
         }
     }
 
-    pub fn load(
-        &self,
-        texture_set_builder: &mut TextureSetBuilder,
-        model_vertices: &mut Vec<ModelVertex>,
-        model_indices: &mut Vec<u32>,
-        model_file: &str,
-        reverse_order: bool,
-    ) -> Result<Model, LoadError> {
-        #[cfg(feature = "debug")]
-        let timer = Timer::new_dynamic(format!("load rsm model from {}", model_file.magenta()));
+    /// Read and parse a model file.
+    fn load_model_data(&self, model_file: &str) -> Result<ModelData, LoadError> {
+        let bytes = self
+            .game_file_loader
+            .get(&format!("data\\model\\{model_file}"))
+            .map_err(LoadError::File)?;
+        let mut byte_reader: ByteReader<Option<InternalVersion>> = ByteReader::with_default_metadata(&bytes);
+        let model_data = ModelData::from_bytes(&mut byte_reader).map_err(LoadError::Conversion)?;
 
-        let bytes = match self.game_file_loader.get(&format!("data\\model\\{model_file}")) {
-            Ok(bytes) => bytes,
-            Err(_error) => {
-                #[cfg(feature = "debug")]
-                {
-                    print_debug!("Failed to load model: {:?}", _error);
-                    print_debug!("Replacing with fallback");
-                }
+        // TODO: Temporary check until we support more versions.
+        // TODO: The model operation to modify texture keyframe is not implemented yet.
+        let version: InternalVersion = model_data.version.into();
+        if version.equals_or_above(2, 4) {
+            return Err(LoadError::UnsupportedFormat(format!("model version {version} is unsupported")));
+        }
 
-                return self.load(
-                    texture_set_builder,
-                    model_vertices,
-                    model_indices,
-                    FALLBACK_MODEL_FILE,
-                    reverse_order,
-                );
-            }
-        };
-        let mut byte_reader: ByteReader<Option<InternalVersion>> = ByteReader::with_default_metadata(&bytes);
+        Ok(model_data)
+    }
 
-        let model_data = match ModelData::from_bytes(&mut byte_reader) {
-            Ok(model_data) => model_data,
-            Err(_error) => {
+    /// Read and parse a model file, replacing it with the fallback model if
+    /// that fails. The fallback is only tried once, so a missing or broken
+    /// fallback model results in an error.
+    fn load_model_data_or_fallback(&self, model_file: &str) -> Result<ModelData, LoadError> {
+        match self.load_model_data(model_file) {
+            Ok(model_data) => Ok(model_data),
+            Err(_error) if model_file != FALLBACK_MODEL_FILE => {
                 #[cfg(feature = "debug")]
                 {
                     print_debug!("Failed to load model: {:?}", _error);
                     print_debug!("Replacing with fallback");
                 }
 
-                return self.load(
-                    texture_set_builder,
-                    model_vertices,
-                    model_indices,
-                    FALLBACK_MODEL_FILE,
-                    reverse_order,
-                );
+                self.load_model_data(FALLBACK_MODEL_FILE)
             }
-        };
+            Err(error) => Err(error),
+        }
+    }
 
-        // TODO: Temporary check until we support more versions.
-        // TODO: The model operation to modify texture keyframe is not implemented yet.
+    /// Load a model and append its geometry to `model_vertices` and
+    /// `model_indices`.
+    ///
+    /// Normals are smoothed if the model uses smooth shading. Setting
+    /// `force_smooth_normals` smooths the normals of flat shaded models as
+    /// well.
+    ///
+    /// The returned [`Model`] only references ranges of these buffers, so it
+    /// can't be shared between different buffers. Callers that load the same
+    /// model multiple times into the same buffers should cache the result (see
+    /// the model cache of the map loader).
+    ///
+    /// If a `progress` callback is given, it is called with the fraction of
+    /// processed nodes, starting at `0.0` and ending at `1.0`.
+    pub fn load(
+        &self,
+        texture_set_builder: &mut TextureSetBuilder,
+        model_vertices: &mut Vec<ModelVertex>,
+        model_indices: &mut Vec<u32>,
+        model_file: &str,
+        winding_order: WindingOrder,
+        force_smooth_normals: bool,
+        progress: Option<&mut dyn FnMut(f32)>,
+    ) -> Result<Model, LoadError> {
+        #[cfg(feature = "debug")]
+        let timer = Timer::new_dynamic(format!("load rsm model from {}", model_file.magenta()));
+
+        let model_data = self.load_model_data_or_fallback(model_file)?;
         let version: InternalVersion = model_data.version.into();
-        if version.equals_or_above(2, 4) {
-            #[cfg(feature = "debug")]
-            {
-                print_debug!("Failed to load model because version {} is unsupported", version);
-                print_debug!("Replacing with fallback");
-            }
 
-            return self.load(
-                texture_set_builder,
-                model_vertices,
-                model_indices,
-                FALLBACK_MODEL_FILE,
-                reverse_order,
+        #[cfg(feature = "debug")]
+        if model_data.nodes.iter().any(|node_data| !node_data.textures_keyframes.is_empty()) {
+            print_debug!(
+                "Texture keyframes of {} are not supported yet and will be ignored",
+                model_file.magenta()
             );
         }
 
@@ -532,7 +663,7 @@ impl ModelLoader {
         let texture_mapping = match version.equals_or_above(2, 3) {
             true => {
                 let model_textures =
-                    HashMap::<String, TextureSetTexture>::from_iter(texture_names.into_iter().zip(model_textures.iter().copied()));
+                    HashMap::<String, TextureSetTexture>::from_iter(texture_names.iter().cloned().zip(model_textures.iter().copied()));
                 TextureMapping::PostVersion2_3(model_textures)
             }
             false => TextureMapping::PreVersion2_3(model_textures),
@@ -558,6 +689,9 @@ impl ModelLoader {
 
         let mut processed_node_indices = vec![false; model_data.nodes.len()];
         let mut model_bounding_box = AABB::uninitialized();
+        let mut load_progress = LoadProgress::new(progress, model_data.nodes.len());
+
+        load_progress.start();
 
         let mut root_nodes: Vec<Node> = root_info
             .into_iter()
@@ -572,17 +706,21 @@ impl ModelLoader {
                     model_vertices,
                     model_indices,
                     &texture_mapping,
+                    &texture_names,
                     &Matrix4::identity(),
                     &mut model_bounding_box,
-                    reverse_order ^ version.equals_or_above(2, 2),
-                    model_data.shade_type == 2,
+                    winding_order.reversed_if(version.equals_or_above(2, 2)),
+                    model_data.shade_type == 2 || force_smooth_normals,
+                    self.wind_affinity_threshold,
                     model_data.frames_per_second.unwrap_or(60.0),
                     model_data.animation_length,
+                    &mut load_progress,
                 )
             })
             .collect();
 
         drop(texture_mapping);
+        load_progress.finish();
 
         let is_static = root_nodes.iter().all(Self::is_static);
 
@@ -702,3 +840,369 @@ enum TextureMapping {
     PreVersion2_3(Vec<TextureSetTexture>),
     PostVersion2_3(HashMap<String, TextureSetTexture>),
 }
+
+/// Reports the fraction of processed nodes while loading a model.
+struct LoadProgress<'a> {
+    callback: Option<&'a mut dyn FnMut(f32)>,
+    node_count: usize,
+    processed_node_count: usize,
+}
+
+impl<'a> LoadProgress<'a> {
+    fn new(callback: Option<&'a mut dyn FnMut(f32)>, node_count: usize) -> Self {
+        Self {
+            callback,
+            node_count,
+            processed_node_count: 0,
+        }
+    }
+
+    fn report(&mut self, progress: f32) {
+        if let Some(callback) = self.callback.as_mut() {
+            callback(progress);
+        }
+    }
+
+    fn start(&mut self) {
+        self.report(0.0);
+    }
+
+    fn node_processed(&mut self) {
+        self.processed_node_count += 1;
+
+        let progress = self.processed_node_count as f32 / self.node_count.max(1) as f32;
+        self.report(progress.min(1.0));
+    }
+
+    /// Nodes that aren't connected to a root node are never processed, so
+    /// we make sure that the last reported progress is always `1.0`.
+    fn finish(&mut self) {
+        if self.processed_node_count < self.node_count {
+            self.report(1.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use cgmath::{InnerSpace, Matrix3, Matrix4, Point3, SquareMatrix, Vector2, Vector3};
+    use korangar_collision::AABB;
+    use ragnarok_formats::model::{FaceData, ModelString, NodeData, TextureCoordinateData};
+    use ragnarok_formats::version::InternalVersion;
+
+    use super::{LoadProgress, ModelLoader, TextureMapping, WindingOrder};
+    use crate::graphics::BindlessSupport;
+    use crate::loaders::error::LoadError;
+    use crate::loaders::{FALLBACK_MODEL_FILE, GameFileLoader, TextureSetTexture};
+
+    const WIND_AFFINITY_THRESHOLD: f32 = 0.5;
+
+    fn node_with_scale(scale: Vector3<f32>) -> NodeData {
+        NodeData {
+            node_name: ModelString { inner: "node".to_owned() },
+            parent_node_name: ModelString { inner: String::new() },
+            texture_count: Some(0),
+            texture_indices: Vec::new(),
+            texture_name_count: None,
+            texture_names: Vec::new(),
+            offset_matrix: Matrix3::identity(),
+            translation1: Some(Vector3::new(0.0, 0.0, 0.0)),
+            translation2: Vector3::new(0.0, 0.0, 0.0),
+            rotation_angle: Some(0.0),
+            rotation_axis: Some(Vector3::new(0.0, 0.0, 0.0)),
+            scale: Some(scale),
+            vertex_position_count: 3,
+            vertex_positions: vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)],
+            texture_coordinate_count: 1,
+            texture_coordinates: vec![TextureCoordinateData {
+                color: None,
+                coordinates: Vector2::new(0.0, 0.0),
+            }],
+            face_count: 1,
+            faces: vec![FaceData {
+                length: None,
+                vertex_position_indices: [0, 1, 2],
+                texture_coordinate_indices: [0, 0, 0],
+                texture_index: 0,
+                padding: 0,
+                two_sided: 0,
+                smooth_group: 0,
+                smooth_group_extra: None,
+            }],
+            scale_keyframe_count: Some(0),
+            scale_keyframes: Vec::new(),
+            rotation_keyframe_count: 0,
+            rotation_keyframes: Vec::new(),
+            translation_keyframe_count: None,
+            translation_keyframes: Vec::new(),
+            textures_keyframe_count: None,
+            textures_keyframes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn negative_scale_reverses_winding() {
+        let node = node_with_scale(Vector3::new(1.0, 1.0, 1.0));
+        let mirrored_node = node_with_scale(Vector3::new(-1.0, 1.0, 1.0));
+
+        let vertices = ModelLoader::make_vertices(
+            &node,
+            &Matrix4::identity(),
+            WindingOrder::Clockwise,
+            false,
+            &[],
+            WIND_AFFINITY_THRESHOLD,
+        );
+        let mirrored_vertices = ModelLoader::make_vertices(
+            &mirrored_node,
+            &Matrix4::identity(),
+            WindingOrder::Clockwise,
+            false,
+            &[],
+            WIND_AFFINITY_THRESHOLD,
+        );
+
+        let positions: Vec<Point3<f32>> = vertices.iter().map(|vertex| vertex.position).collect();
+        let mirrored_positions: Vec<Point3<f32>> = mirrored_vertices.iter().rev().map(|vertex| vertex.position).collect();
+        assert_eq!(positions, mirrored_positions);
+
+        for vertex in vertices.iter().chain(mirrored_vertices.iter()) {
+            assert_eq!(vertex.normal, Vector3::new(0.0, -1.0, 0.0));
+        }
+    }
+
+    fn two_node_model() -> [NodeData; 2] {
+        let mut root_node = node_with_scale(Vector3::new(1.0, 1.0, 1.0));
+        root_node.texture_indices = vec![0];
+
+        let mut child_node = node_with_scale(Vector3::new(1.0, 1.0, 1.0));
+        child_node.node_name = ModelString { inner: "child".to_owned() };
+        child_node.parent_node_name = ModelString { inner: "node".to_owned() };
+        child_node.texture_indices = vec![0];
+        child_node.vertex_positions = vec![Point3::new(5.0, 0.0, 0.0), Point3::new(5.0, 2.0, 1.0), Point3::new(6.0, 0.0, 0.0)];
+
+        [root_node, child_node]
+    }
+
+    fn single_texture_mapping() -> TextureMapping {
+        TextureMapping::PreVersion2_3(vec![TextureSetTexture {
+            index: 0,
+            width: 1,
+            height: 1,
+            is_transparent: false,
+        }])
+    }
+
+    #[test]
+    fn winding_order() {
+        let node = node_with_scale(Vector3::new(1.0, 1.0, 1.0));
+
+        let winding = |winding_order| {
+            let vertices = ModelLoader::make_vertices(&node, &Matrix4::identity(), winding_order, false, &[], WIND_AFFINITY_THRESHOLD);
+            let face_normal = (vertices[1].position - vertices[0].position).cross(vertices[2].position - vertices[0].position);
+            face_normal.dot(vertices[0].normal)
+        };
+
+        assert!(winding(WindingOrder::Clockwise) < 0.0);
+        assert!(winding(WindingOrder::CounterClockwise) > 0.0);
+    }
+
+    #[test]
+    fn wind_affinity_above_threshold() {
+        let mut node = node_with_scale(Vector3::new(1.0, 1.0, 1.0));
+        // The Y axis of models points downwards, so the second vertex is the top of
+        // the node.
+        node.vertex_positions = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(0.0, -10.0, 0.0),
+            Point3::new(1.0, -2.0, 0.0),
+        ];
+
+        let vertices = ModelLoader::make_vertices(
+            &node,
+            &Matrix4::identity(),
+            WindingOrder::Clockwise,
+            false,
+            &[true],
+            WIND_AFFINITY_THRESHOLD,
+        );
+
+        assert_eq!(vertices[0].wind_affinity, 0.0);
+        assert_eq!(vertices[1].wind_affinity, 1.0);
+        assert_eq!(vertices[2].wind_affinity, 0.0);
+
+        // Faces with textures that aren't affected by wind never move.
+        let vertices = ModelLoader::make_vertices(
+            &node,
+            &Matrix4::identity(),
+            WindingOrder::Clockwise,
+            false,
+            &[false],
+            WIND_AFFINITY_THRESHOLD,
+        );
+
+        assert!(vertices.iter().all(|vertex| vertex.wind_affinity == 0.0));
+    }
+
+    #[test]
+    fn wind_affected_texture_names() {
+        assert!(ModelLoader::is_affected_by_wind("texture\\Tree_Leaves01.bmp"));
+        assert!(ModelLoader::is_affected_by_wind("texture\\배경\\풀01.bmp"));
+        assert!(!ModelLoader::is_affected_by_wind("texture\\prontera\\wall01.bmp"));
+    }
+
+    #[test]
+    fn smooth_normals_of_shared_vertices() {
+        // Two faces that share the edge from the origin to (0, 0, 1) and are bent
+        // against each other, like a coarse section of a curved surface.
+        let mut node = node_with_scale(Vector3::new(1.0, 1.0, 1.0));
+        node.vertex_position_count = 4;
+        node.vertex_positions.push(Point3::new(-1.0, -1.0, 0.0));
+        node.face_count = 2;
+        node.faces.push(FaceData {
+            length: None,
+            vertex_position_indices: [1, 0, 3],
+            texture_coordinate_indices: [0, 0, 0],
+            texture_index: 0,
+            padding: 0,
+            two_sided: 0,
+            smooth_group: 0,
+            smooth_group_extra: None,
+        });
+
+        let flat_vertices = ModelLoader::make_vertices(
+            &node,
+            &Matrix4::identity(),
+            WindingOrder::Clockwise,
+            false,
+            &[],
+            WIND_AFFINITY_THRESHOLD,
+        );
+        let smooth_vertices = ModelLoader::make_vertices(
+            &node,
+            &Matrix4::identity(),
+            WindingOrder::Clockwise,
+            true,
+            &[],
+            WIND_AFFINITY_THRESHOLD,
+        );
+
+        let first_face_normal = flat_vertices[0].normal;
+        let second_face_normal = flat_vertices[3].normal;
+        let averaged_normal = (first_face_normal + second_face_normal).normalize();
+
+        assert!((first_face_normal - second_face_normal).magnitude() > 0.1);
+
+        for vertex in smooth_vertices.iter() {
+            let mut flat_at_position = flat_vertices.iter().filter(|flat| flat.position == vertex.position);
+            let first = flat_at_position.next().unwrap();
+
+            // Only the vertices on the shared edge get an averaged normal.
+            let expected_normal = match flat_at_position.next() {
+                Some(_) => averaged_normal,
+                None => first.normal,
+            };
+
+            assert!((vertex.normal - expected_normal).magnitude() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn missing_fallback_model() {
+        // A game file loader without any archives can't find the fallback model
+        // either, so loading has to fail instead of trying the fallback forever.
+        let model_loader = ModelLoader::new(
+            Arc::new(GameFileLoader::default()),
+            BindlessSupport::None,
+            WIND_AFFINITY_THRESHOLD,
+        );
+
+        assert!(matches!(
+            model_loader.load_model_data_or_fallback("prontera/fountain.rsm"),
+            Err(LoadError::File(_))
+        ));
+        assert!(matches!(
+            model_loader.load_model_data_or_fallback(FALLBACK_MODEL_FILE),
+            Err(LoadError::File(_))
+        ));
+    }
+
+    #[test]
+    fn node_bounding_boxes() {
+        let nodes = two_node_model();
+        let texture_mapping = single_texture_mapping();
+        let mut processed_node_indices = [true, false];
+        let mut model_bounding_box = AABB::uninitialized();
+
+        let node = ModelLoader::process_node_mesh(
+            BindlessSupport::Full,
+            InternalVersion { major: 1, minor: 5 },
+            &nodes[0],
+            &nodes,
+            &mut processed_node_indices,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &texture_mapping,
+            &[],
+            &Matrix4::identity(),
+            &mut model_bounding_box,
+            WindingOrder::Clockwise,
+            false,
+            WIND_AFFINITY_THRESHOLD,
+            60.0,
+            0,
+            &mut LoadProgress::new(None, nodes.len()),
+        );
+
+        assert_eq!(node.child_nodes.len(), 1);
+
+        let child = &node.child_nodes[0];
+        assert!(child.bounding_box.max().x > node.bounding_box.max().x);
+
+        for bounding_box in [node.bounding_box, child.bounding_box] {
+            let min = bounding_box.min();
+            let max = bounding_box.max();
+
+            assert!(min.x >= model_bounding_box.min().x && min.y >= model_bounding_box.min().y && min.z >= model_bounding_box.min().z);
+            assert!(max.x <= model_bounding_box.max().x && max.y <= model_bounding_box.max().y && max.z <= model_bounding_box.max().z);
+        }
+    }
+
+    #[test]
+    fn load_progress() {
+        let nodes = two_node_model();
+        let texture_mapping = single_texture_mapping();
+        let mut reported_progress = Vec::new();
+        let mut callback = |progress: f32| reported_progress.push(progress);
+        let mut load_progress = LoadProgress::new(Some(&mut callback), nodes.len());
+
+        load_progress.start();
+        ModelLoader::process_node_mesh(
+            BindlessSupport::Full,
+            InternalVersion { major: 1, minor: 5 },
+            &nodes[0],
+            &nodes,
+            &mut [true, false],
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &texture_mapping,
+            &[],
+            &Matrix4::identity(),
+            &mut AABB::uninitialized(),
+            WindingOrder::Clockwise,
+            false,
+            WIND_AFFINITY_THRESHOLD,
+            60.0,
+            0,
+            &mut load_progress,
+        );
+        load_progress.finish();
+
+        assert_eq!(reported_progress.first(), Some(&0.0));
+        assert_eq!(reported_progress.last(), Some(&1.0));
+        assert!(reported_progress.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(reported_progress.len(), nodes.len() + 1);
+    }
+}
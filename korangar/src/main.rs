@@ -49,7 +49,7 @@ use std::ops::Deref;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock, Mutex};
 
-use cgmath::{Point3, Vector3};
+use cgmath::{Deg, Point3, Vector3};
 use image::{EncodableLayout, ImageFormat, ImageReader};
 use input::{MouseInputMode, MouseModeExt};
 use inventory::{HotbarPathExt, InventoryPathExt, SkillTreePathExt};
@@ -71,7 +71,7 @@ use networking::{PacketHistory, PacketHistoryCallback};
 #[cfg(not(feature = "debug"))]
 use ragnarok_packets::handler::NoPacketCallback;
 use ragnarok_packets::{
-    BuyShopItemsResult, CharacterServerInformation, Direction, DisappearanceReason, HotbarSlot, SellItemsResult, SkillId, SkillType,
+    BuyShopItemsResult, CharacterServerInformation, Direction, DisappearanceReason, HotbarSlot, SellItemsResult, SkillId, SkillTypeFlags,
     TilePosition, UnitId, WorldPosition,
 };
 use renderer::InterfaceRenderer;
@@ -79,7 +79,8 @@ use rust_state::{Context, ManuallyAssertExt};
 #[cfg(feature = "debug")]
 use rust_state::{VecIndexExt, VecLookupExt};
 use settings::{
-    AudioSettings, AudioSettingsPathExt, GraphicsSettingsCapabilities, GraphicsSettingsPathExt, InterfaceSettings, InterfaceSettingsPathExt,
+    AudioSettings, AudioSettingsPathExt, GraphicsSettingsCapabilities, GraphicsSettingsPathExt, InterfaceSettings,
+    InterfaceSettingsPathExt, MonitorBounds, WindowSettings,
 };
 use state::localization::Localization;
 use state::theme::{CursorThemePathExt, IndicatorThemePathExt, InterfaceThemePathExt, WorldThemePathExt};
@@ -93,7 +94,7 @@ use wgpu::{
     Gles3MinorVersion, Instance, InstanceDescriptor, InstanceFlags, MemoryBudgetThresholds, MemoryHints, NoopBackendOptions, Queue, Trace,
 };
 use winit::application::ApplicationHandler;
-use winit::dpi::{LogicalSize, PhysicalSize};
+use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize};
 use winit::event::WindowEvent;
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
 use winit::keyboard::PhysicalKey;
@@ -431,6 +432,7 @@ struct Client {
     audio_engine: Arc<AudioEngine<GameFileLoader>>,
     active_interface_settings: InterfaceSettings,
     active_graphics_settings: GraphicsSettings,
+    window_settings: WindowSettings,
     graphics_engine: GraphicsEngine,
     queue: Queue,
     #[cfg(feature = "debug")]
@@ -531,7 +533,11 @@ impl Client {
             std::fs::create_dir_all(IN_GAME_THEMES_PATH).unwrap();
             std::fs::create_dir_all(WORLD_THEMES_PATH).unwrap();
 
-            let model_loader = Arc::new(ModelLoader::new(game_file_loader.clone(), capabilities.bindless_support()));
+            let model_loader = Arc::new(ModelLoader::new(
+                game_file_loader.clone(),
+                capabilities.bindless_support(),
+                graphics_settings.wind_affinity_threshold,
+            ));
             let texture_loader = Arc::new(TextureLoader::new(
                 device.clone(),
                 queue.clone(),
@@ -831,6 +837,7 @@ impl Client {
             audio_engine,
             active_interface_settings,
             active_graphics_settings: graphics_settings,
+            window_settings: WindowSettings::new(),
             graphics_engine,
             queue,
             #[cfg(feature = "debug")]
@@ -2136,21 +2143,21 @@ impl Client {
                 },
                 InputEvent::CastSkill { slot } => {
                     if let Some(skill) = self.client_state.follow(client_state().hotbar()).get_skill_in_slot(slot).as_ref() {
-                        match skill.skill_type {
-                            SkillType::Passive => {}
-                            SkillType::Attack => {
-                                if let PickerTarget::Entity(entity_id) = input_report.mouse_target {
-                                    let _ = self.networking_system.cast_skill(skill.skill_id, skill.skill_level, entity_id);
-                                }
-                            }
-                            SkillType::Ground | SkillType::Trap => {
-                                if let PickerTarget::Tile { x, y } = input_report.mouse_target {
-                                    let _ = self
-                                        .networking_system
-                                        .cast_ground_skill(skill.skill_id, skill.skill_level, TilePosition { x, y });
-                                }
-                            }
-                            SkillType::SelfCast => match skill.skill_id == ROLLING_CUTTER_ID {
+                        // Skills can have multiple types, so we pick the one that fits the target.
+                        let skill_type = skill.skill_type;
+
+                        if skill_type.contains(SkillTypeFlags::ATTACK)
+                            && let PickerTarget::Entity(entity_id) = input_report.mouse_target
+                        {
+                            let _ = self.networking_system.cast_skill(skill.skill_id, skill.skill_level, entity_id);
+                        } else if skill_type.intersects(SkillTypeFlags::GROUND | SkillTypeFlags::TRAP)
+                            && let PickerTarget::Tile { x, y } = input_report.mouse_target
+                        {
+                            let _ = self
+                                .networking_system
+                                .cast_ground_skill(skill.skill_id, skill.skill_level, TilePosition { x, y });
+                        } else if skill_type.contains(SkillTypeFlags::SELF_CAST) {
+                            match skill.skill_id == ROLLING_CUTTER_ID {
                                 true => {
                                     let _ = self.networking_system.cast_channeling_skill(
                                         skill.skill_id,
@@ -2165,17 +2172,16 @@ impl Client {
                                         self.client_state.follow(this_entity().manually_asserted()).get_entity_id(),
                                     );
                                 }
-                            },
-                            SkillType::Support => {
-                                if let PickerTarget::Entity(entity_id) = input_report.mouse_target {
-                                    let _ = self.networking_system.cast_skill(skill.skill_id, skill.skill_level, entity_id);
-                                } else {
-                                    let _ = self.networking_system.cast_skill(
-                                        skill.skill_id,
-                                        skill.skill_level,
-                                        self.client_state.follow(this_entity().manually_asserted()).get_entity_id(),
-                                    );
-                                }
+                            }
+                        } else if skill_type.contains(SkillTypeFlags::SUPPORT) {
+                            if let PickerTarget::Entity(entity_id) = input_report.mouse_target {
+                                let _ = self.networking_system.cast_skill(skill.skill_id, skill.skill_level, entity_id);
+                            } else {
+                                let _ = self.networking_system.cast_skill(
+                                    skill.skill_id,
+                                    skill.skill_level,
+                                    self.client_state.follow(this_entity().manually_asserted()).get_entity_id(),
+                                );
                             }
                         }
                     }
@@ -2501,6 +2507,9 @@ impl Client {
             let screen_size: ScreenSize = window_size.into();
 
             if self.client_state.try_follow(this_entity()).is_some() {
+                let field_of_view = *self.client_state.follow(client_state().graphics_settings().field_of_view());
+
+                self.player_camera.set_vertical_fov(Deg(field_of_view));
                 self.player_camera.update(delta_time);
                 self.player_camera.generate_view_projection(window_size);
             } else {
@@ -2607,6 +2616,9 @@ impl Client {
             let shadow_method = *self.client_state.follow(client_state().graphics_settings().shadow_method());
             let shadow_detail = *self.client_state.follow(client_state().graphics_settings().shadow_detail());
             let sdsm_enabled = *self.client_state.follow(client_state().graphics_settings().sdsm());
+            let view_distance = *self.client_state.follow(client_state().graphics_settings().view_distance());
+            let gamma = *self.client_state.follow(client_state().graphics_settings().gamma());
+            let brightness = *self.client_state.follow(client_state().graphics_settings().brightness());
             let use_sdsm = sdsm_enabled & !self.player_camera.is_rotating_or_zooming_fast();
 
             let ambient_light_color = map.ambient_light_color();
@@ -2629,6 +2641,7 @@ impl Client {
                         &view_matrix,
                         &projection_matrix,
                         shadow_resolution.directional_shadow_resolution(),
+                        shadow_detail.cascade_count(),
                     );
                 }
             }
@@ -2681,8 +2694,10 @@ impl Client {
                 map.register_point_lights(&mut self.point_light_manager, &mut self.point_light_set_buffer, current_camera);
 
                 match lighting_mode {
-                    LightingMode::Classic => self.point_light_manager.create_point_light_set(0),
-                    LightingMode::Enhanced => self.point_light_manager.create_point_light_set(NUMBER_OF_POINT_LIGHTS_WITH_SHADOWS),
+                    LightingMode::Enhanced if shadow_detail.enable_point_shadows() => {
+                        self.point_light_manager.create_point_light_set(NUMBER_OF_POINT_LIGHTS_WITH_SHADOWS)
+                    }
+                    LightingMode::Classic | LightingMode::Enhanced => self.point_light_manager.create_point_light_set(0),
                 }
             };
 
@@ -2727,11 +2742,12 @@ impl Client {
 
             // Directional Shadows
             {
-                for partition_index in 0..PARTITION_COUNT {
+                for partition_index in 0..self.directional_shadow_camera.cascade_count() {
                     let partition_camera = self.directional_shadow_camera.get_partition_camera(partition_index);
 
                     let object_set = map.cull_objects_with_frustum(
                         &partition_camera,
+                        ViewDistance::Unlimited,
                         &mut self.directional_shadow_object_set_buffer,
                         #[cfg(feature = "debug")]
                         render_options.frustum_culling,
@@ -2819,6 +2835,7 @@ impl Client {
             {
                 let object_set = map.cull_objects_with_frustum(
                     current_camera,
+                    view_distance,
                     &mut self.deferred_object_set_buffer,
                     #[cfg(feature = "debug")]
                     render_options.frustum_culling,
@@ -2904,6 +2921,7 @@ impl Client {
 
                     let object_set = map.cull_objects_with_frustum(
                         culling_camera,
+                        view_distance,
                         &mut self.bounding_box_object_set_buffer,
                         #[cfg(feature = "debug")]
                         render_options.frustum_culling,
@@ -3197,6 +3215,8 @@ impl Client {
                     shadow_detail,
                     use_sdsm,
                     sdsm_enabled,
+                    gamma,
+                    brightness,
                 },
                 indicator: indicator_instruction,
                 interface: interface_instructions.as_slice(),
@@ -3258,9 +3278,9 @@ impl Client {
     fn update_settings(&mut self) {
         let graphics_settings = self.client_state.follow(client_state().graphics_settings());
 
-        if self.active_graphics_settings.vsync != graphics_settings.vsync {
-            self.graphics_engine.set_vsync(graphics_settings.vsync);
-            self.active_graphics_settings.vsync = graphics_settings.vsync;
+        if self.active_graphics_settings.present_mode != graphics_settings.present_mode {
+            self.graphics_engine.set_present_mode(graphics_settings.present_mode);
+            self.active_graphics_settings.present_mode = graphics_settings.present_mode;
         }
 
         if self.active_graphics_settings.limit_framerate != graphics_settings.limit_framerate {
@@ -3294,9 +3314,19 @@ impl Client {
             self.active_graphics_settings.screen_space_anti_aliasing = graphics_settings.screen_space_anti_aliasing;
         }
 
-        if self.active_graphics_settings.shadow_resolution != graphics_settings.shadow_resolution {
-            self.graphics_engine.set_shadow_resolution(graphics_settings.shadow_resolution);
+        // The size of the point shadow maps depends on the shadow detail.
+        if self.active_graphics_settings.shadow_resolution != graphics_settings.shadow_resolution
+            || self.active_graphics_settings.shadow_detail != graphics_settings.shadow_detail
+        {
+            self.graphics_engine
+                .set_shadow_resolution(graphics_settings.shadow_resolution, graphics_settings.shadow_detail);
             self.active_graphics_settings.shadow_resolution = graphics_settings.shadow_resolution;
+            self.active_graphics_settings.shadow_detail = graphics_settings.shadow_detail;
+        }
+
+        if self.active_graphics_settings.shadow_bias != graphics_settings.shadow_bias {
+            self.graphics_engine.set_shadow_bias(graphics_settings.shadow_bias);
+            self.active_graphics_settings.shadow_bias = graphics_settings.shadow_bias;
         }
 
         if self.active_graphics_settings.high_quality_interface != graphics_settings.high_quality_interface {
@@ -3356,14 +3386,31 @@ impl ApplicationHandler for Client {
                 assert_eq!(image_buffer.width(), image_buffer.height(), "icon must be square");
                 let icon = Icon::from_rgba(image_data, image_buffer.width(), image_buffer.height()).unwrap();
 
-                let window_attributes = Window::default_attributes()
+                let monitors: Vec<MonitorBounds> = event_loop
+                    .available_monitors()
+                    .map(|monitor| MonitorBounds {
+                        x: monitor.position().x,
+                        y: monitor.position().y,
+                        width: monitor.size().width,
+                        height: monitor.size().height,
+                        scale_factor: monitor.scale_factor(),
+                    })
+                    .collect();
+
+                let mut window_attributes = Window::default_attributes()
                     .with_inner_size(LogicalSize {
-                        width: INITIAL_SCREEN_SIZE.width,
-                        height: INITIAL_SCREEN_SIZE.height,
+                        width: self.window_settings.width,
+                        height: self.window_settings.height,
                     })
+                    .with_maximized(self.window_settings.maximized)
                     .with_title(CLIENT_NAME)
                     .with_window_icon(Some(icon))
                     .with_visible(false);
+
+                if let Some((x, y)) = self.window_settings.validated_position(&monitors) {
+                    window_attributes = window_attributes.with_position(PhysicalPosition::new(x, y));
+                }
+
                 let window = Arc::new(event_loop.create_window(window_attributes).unwrap());
 
                 let backend_name = self.graphics_engine.get_backend_name();
@@ -3386,9 +3433,11 @@ impl ApplicationHandler for Client {
             self.graphics_engine.on_resume(
                 window.clone(),
                 graphics_settings.triple_buffering,
-                graphics_settings.vsync,
+                graphics_settings.present_mode,
                 graphics_settings.limit_framerate,
                 graphics_settings.shadow_resolution,
+                graphics_settings.shadow_detail,
+                graphics_settings.shadow_bias,
                 graphics_settings.texture_filtering,
                 graphics_settings.msaa,
                 graphics_settings.ssaa,
@@ -3432,9 +3481,28 @@ impl ApplicationHandler for Client {
                 self.effect_renderer.update_window_size(screen_size);
 
                 if let Some(window) = self.window.as_ref() {
+                    self.window_settings.maximized = window.is_maximized();
+
+                    if !self.window_settings.maximized {
+                        let logical_size = window.inner_size().to_logical::<u32>(window.scale_factor());
+                        self.window_settings.width = logical_size.width;
+                        self.window_settings.height = logical_size.height;
+                    }
+
                     window.request_redraw();
                 }
             }
+            WindowEvent::Moved(position) => {
+                if let Some(window) = self.window.as_ref().filter(|window| !window.is_maximized()) {
+                    let current_monitor = window.current_monitor();
+
+                    self.window_settings.position = Some((position.x, position.y));
+                    self.window_settings.monitor_index = window
+                        .available_monitors()
+                        .position(|monitor| Some(monitor) == current_monitor)
+                        .unwrap_or_default();
+                }
+            }
             WindowEvent::Focused(focused) => {
                 if !focused {
                     self.input_system.reset();
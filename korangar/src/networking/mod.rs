@@ -185,13 +185,18 @@ impl Packet for UnknownPacket {
     const HEADER: PacketHeader = PacketHeader(0);
     const IS_PING: bool = false;
 
+    /// The size of an unknown packet can't be determined, so this consumes
+    /// everything that is left in the reader. To limit the packet to a known
+    /// length, read the bytes with [`ByteReader::slice`] first.
     fn payload_from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        let _ = byte_reader;
-        unimplemented!()
+        Ok(Self {
+            bytes: byte_reader.remaining_bytes(),
+        })
     }
 
-    fn payload_to_bytes(&self, _byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
-        unimplemented!()
+    fn payload_to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        byte_writer.extend_from_slice(&self.bytes);
+        Ok(self.bytes.len())
     }
 
     fn to_element<App: korangar_interface::application::Application>(
@@ -517,3 +522,43 @@ impl PacketCallback for PacketHistoryCallback {
         }));
     }
 }
+
+#[cfg(test)]
+mod unknown_packet {
+    use ragnarok_bytes::{ByteReader, ByteWriter};
+    use ragnarok_packets::Packet;
+
+    use super::UnknownPacket;
+
+    #[test]
+    fn captures_remaining_bytes() {
+        let bytes = [0x34, 0x12, 0xAB, 0xCD, 0xEF];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = UnknownPacket::payload_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.bytes, bytes);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn captures_sliced_bytes() {
+        let bytes = [0xAB, 0xCD, 0xEF];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let slice = byte_reader.slice::<UnknownPacket>(2).unwrap();
+        let packet = UnknownPacket::payload_from_bytes(&mut ByteReader::without_metadata(slice)).unwrap();
+
+        assert_eq!(packet.bytes, [0xAB, 0xCD]);
+        assert!(!byte_reader.is_empty());
+    }
+
+    #[test]
+    fn writes_bytes_verbatim() {
+        let packet = UnknownPacket {
+            bytes: vec![0x34, 0x12, 0xAB],
+        };
+        let mut byte_writer = ByteWriter::new();
+
+        assert_eq!(packet.payload_to_bytes(&mut byte_writer).unwrap(), 3);
+        assert_eq!(byte_writer.into_inner(), [0x34, 0x12, 0xAB]);
+    }
+}
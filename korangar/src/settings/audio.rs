@@ -1,10 +1,11 @@
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, print_debug};
 use korangar_interface::element::StateElement;
-use ron::ser::PrettyConfig;
 use rust_state::RustState;
 use serde::{Deserialize, Serialize};
 
+use super::save_settings_file;
+
 #[derive(Clone, Serialize, Deserialize, RustState, StateElement)]
 pub struct AudioSettings {
     pub mute_on_focus_loss: bool,
@@ -35,13 +36,17 @@ impl AudioSettings {
             .and_then(|data| ron::from_str(&data).ok())
     }
 
-    pub fn save(&self) {
+    pub fn save(&self) -> std::io::Result<()> {
         #[cfg(feature = "debug")]
         print_debug!("saving audio settings to {}", Self::FILE_NAME.magenta());
 
-        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+        save_settings_file(Self::FILE_NAME, self)
+    }
+}
 
-        if let Err(_error) = std::fs::write(Self::FILE_NAME, data) {
+impl Drop for AudioSettings {
+    fn drop(&mut self) {
+        if let Err(_error) = self.save() {
             #[cfg(feature = "debug")]
             print_debug!(
                 "failed to save audio settings to {}: {:?}",
@@ -51,9 +56,3 @@ impl AudioSettings {
         }
     }
 }
-
-impl Drop for AudioSettings {
-    fn drop(&mut self) {
-        self.save();
-    }
-}
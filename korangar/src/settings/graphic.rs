@@ -1,19 +1,41 @@
+use std::ops::RangeInclusive;
+use std::path::Path;
+
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, print_debug};
 use korangar_interface::components::drop_down::DropDownItem;
 use korangar_interface::element::StateElement;
-use ron::ser::PrettyConfig;
 use rust_state::RustState;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
+use super::{SettingsError, read_settings_file, save_settings_file};
 use crate::graphics::{
-    LimitFramerate, Msaa, PresentModeInfo, ScreenSpaceAntiAliasing, ShadowDetail, ShadowMethod, ShadowResolution, Ssaa, TextureSamplerType,
+    LimitFramerate, Msaa, PresentMode, PresentModeInfo, ScreenSpaceAntiAliasing, ShadowBias, ShadowDetail, ShadowMethod, ShadowResolution,
+    Ssaa, TextureSamplerType, ViewDistance,
 };
 
+/// Range of the gamma that is applied to the final image.
+pub const GAMMA_RANGE: RangeInclusive<f32> = 0.5..=2.5;
+/// Range of the brightness that is applied to the final image.
+pub const BRIGHTNESS_RANGE: RangeInclusive<f32> = 0.5..=2.0;
+/// Range of the vertical field of view of the player camera in degrees. The
+/// camera is far away from the player, so even small changes have a big
+/// effect.
+pub const FIELD_OF_VIEW_RANGE: RangeInclusive<f32> = 10.0..=45.0;
+/// Range of the relative height inside of a model node above which vertices are
+/// affected by wind.
+const WIND_AFFINITY_THRESHOLD_RANGE: RangeInclusive<f32> = 0.0..=1.0;
+
 #[derive(Clone, Serialize, Deserialize, RustState, StateElement)]
 pub struct GraphicsSettings {
     pub lighting_mode: LightingMode,
-    pub vsync: bool,
+    #[serde(default)]
+    pub present_mode: PresentMode,
+    /// Setting used before [`PresentMode`] was introduced. Only read to migrate
+    /// old settings files.
+    #[serde(default, skip_serializing, deserialize_with = "deserialize_vsync")]
+    #[hidden_element]
+    vsync: Option<bool>,
     pub limit_framerate: LimitFramerate,
     pub triple_buffering: bool,
     pub texture_filtering: TextureSamplerType,
@@ -23,7 +45,26 @@ pub struct GraphicsSettings {
     pub shadow_method: ShadowMethod,
     pub shadow_resolution: ShadowResolution,
     pub shadow_detail: ShadowDetail,
+    #[serde(default)]
+    pub shadow_bias: ShadowBias,
     pub sdsm: bool,
+    #[serde(default)]
+    pub view_distance: ViewDistance,
+    #[serde(default = "default_gamma", deserialize_with = "deserialize_gamma")]
+    pub gamma: f32,
+    #[serde(default = "default_brightness", deserialize_with = "deserialize_brightness")]
+    pub brightness: f32,
+    /// Vertical field of view of the player camera in degrees.
+    #[serde(default = "default_field_of_view", deserialize_with = "deserialize_field_of_view")]
+    pub field_of_view: f32,
+    /// Relative height inside of a model node above which vertices sway in the
+    /// wind. Only read when the client starts, since it is baked into the
+    /// vertices of the models.
+    #[serde(
+        default = "default_wind_affinity_threshold",
+        deserialize_with = "deserialize_wind_affinity_threshold"
+    )]
+    pub wind_affinity_threshold: f32,
     pub high_quality_interface: bool,
 }
 
@@ -31,7 +72,8 @@ impl Default for GraphicsSettings {
     fn default() -> Self {
         Self {
             lighting_mode: LightingMode::Enhanced,
-            vsync: true,
+            present_mode: PresentMode::Fifo,
+            vsync: None,
             limit_framerate: LimitFramerate::Unlimited,
             triple_buffering: true,
             texture_filtering: TextureSamplerType::Anisotropic(4),
@@ -41,7 +83,13 @@ impl Default for GraphicsSettings {
             shadow_method: ShadowMethod::SoftPCSS,
             shadow_resolution: ShadowResolution::Normal,
             shadow_detail: ShadowDetail::Medium,
+            shadow_bias: ShadowDetail::Medium.shadow_bias(),
             sdsm: true,
+            view_distance: ViewDistance::Unlimited,
+            gamma: default_gamma(),
+            brightness: default_brightness(),
+            field_of_view: default_field_of_view(),
+            wind_affinity_threshold: default_wind_affinity_threshold(),
             high_quality_interface: true,
         }
     }
@@ -51,30 +99,89 @@ impl GraphicsSettings {
     const FILE_NAME: &'static str = "client/graphics_settings.ron";
 
     pub fn new() -> Self {
-        Self::load().unwrap_or_else(|| {
+        Self::load().unwrap_or_else(|_error| {
             #[cfg(feature = "debug")]
-            print_debug!("failed to load graphics settings from {}", Self::FILE_NAME.magenta());
+            print_debug!(
+                "failed to load graphics settings from {}: {:?}",
+                Self::FILE_NAME.magenta(),
+                _error.red()
+            );
 
             Default::default()
         })
     }
 
-    pub fn load() -> Option<Self> {
+    pub fn load() -> Result<Self, SettingsError> {
         #[cfg(feature = "debug")]
         print_debug!("loading graphics settings from {}", Self::FILE_NAME.magenta());
 
-        std::fs::read_to_string(Self::FILE_NAME)
-            .ok()
-            .and_then(|data| ron::from_str(&data).ok())
+        Self::load_from(Self::FILE_NAME)
     }
 
-    pub fn save(&self) {
+    fn load_from(path: impl AsRef<Path>) -> Result<Self, SettingsError> {
+        let data = read_settings_file(path)?;
+        Self::from_ron(&data).map_err(SettingsError::Parse)
+    }
+
+    fn from_ron(data: &str) -> Result<Self, ron::error::SpannedError> {
+        let mut settings: Self = ron::from_str(data)?;
+
+        if let Some(vsync) = settings.vsync.take() {
+            settings.present_mode = PresentMode::from_vsync(vsync);
+        }
+
+        Ok(settings)
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
         #[cfg(feature = "debug")]
         print_debug!("saving graphics settings to {}", Self::FILE_NAME.magenta());
 
-        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+        save_settings_file(Self::FILE_NAME, self)
+    }
+}
+
+fn deserialize_vsync<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+    bool::deserialize(deserializer).map(Some)
+}
+
+fn default_gamma() -> f32 {
+    1.0
+}
+
+fn deserialize_gamma<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    f32::deserialize(deserializer).map(|gamma| gamma.clamp(*GAMMA_RANGE.start(), *GAMMA_RANGE.end()))
+}
+
+fn default_brightness() -> f32 {
+    1.0
+}
+
+fn deserialize_brightness<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    f32::deserialize(deserializer).map(|brightness| brightness.clamp(*BRIGHTNESS_RANGE.start(), *BRIGHTNESS_RANGE.end()))
+}
+
+fn default_field_of_view() -> f32 {
+    15.5
+}
+
+fn deserialize_field_of_view<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    f32::deserialize(deserializer).map(|field_of_view| field_of_view.clamp(*FIELD_OF_VIEW_RANGE.start(), *FIELD_OF_VIEW_RANGE.end()))
+}
+
+fn default_wind_affinity_threshold() -> f32 {
+    0.5
+}
+
+fn deserialize_wind_affinity_threshold<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    f32::deserialize(deserializer).map(|wind_affinity_threshold| {
+        wind_affinity_threshold.clamp(*WIND_AFFINITY_THRESHOLD_RANGE.start(), *WIND_AFFINITY_THRESHOLD_RANGE.end())
+    })
+}
 
-        if let Err(_error) = std::fs::write(Self::FILE_NAME, data) {
+impl Drop for GraphicsSettings {
+    fn drop(&mut self) {
+        if let Err(_error) = self.save() {
             #[cfg(feature = "debug")]
             print_debug!(
                 "failed to save graphics settings to {}: {:?}",
@@ -85,12 +192,6 @@ impl GraphicsSettings {
     }
 }
 
-impl Drop for GraphicsSettings {
-    fn drop(&mut self) {
-        self.save();
-    }
-}
-
 /// The lighting mode used when rendering the game.
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, StateElement)]
 pub enum LightingMode {
@@ -124,7 +225,8 @@ pub struct GraphicsSettingsCapabilities {
     shadow_method_options: Vec<ShadowMethod>,
     shadow_resolution_options: Vec<ShadowResolution>,
     shadow_detail_options: Vec<ShadowDetail>,
-    vsync_setting_disabled: bool,
+    view_distance_options: Vec<ViewDistance>,
+    present_mode_options: Vec<PresentMode>,
 }
 
 impl Default for GraphicsSettingsCapabilities {
@@ -152,7 +254,8 @@ impl Default for GraphicsSettingsCapabilities {
             shadow_method_options: vec![ShadowMethod::Hard, ShadowMethod::SoftPCF, ShadowMethod::SoftPCSS],
             shadow_resolution_options: vec![ShadowResolution::Normal, ShadowResolution::Ultra, ShadowResolution::Insane],
             shadow_detail_options: vec![ShadowDetail::Low, ShadowDetail::Medium, ShadowDetail::High, ShadowDetail::Ultra],
-            vsync_setting_disabled: true,
+            view_distance_options: vec![ViewDistance::Near, ViewDistance::Medium, ViewDistance::Far, ViewDistance::Unlimited],
+            present_mode_options: vec![PresentMode::Fifo],
         }
     }
 }
@@ -160,6 +263,190 @@ impl Default for GraphicsSettingsCapabilities {
 impl GraphicsSettingsCapabilities {
     pub fn update(&mut self, supported_msaa: Vec<Msaa>, present_mode_info: PresentModeInfo) {
         self.supported_msaa = supported_msaa;
-        self.vsync_setting_disabled = !present_mode_info.supports_mailbox && !present_mode_info.supports_immediate;
+        self.present_mode_options = present_mode_info.supported_present_modes();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::ManuallyDrop;
+
+    use ron::ser::PrettyConfig;
+
+    use super::{GraphicsSettings, LightingMode};
+    use crate::graphics::{LimitFramerate, PresentMode, ShadowBias, ShadowDetail, ViewDistance};
+    use crate::settings::SettingsError;
+
+    #[test]
+    fn shadow_bias_round_trip() {
+        // Graphics settings are saved when dropped, so we need to make sure that
+        // the test doesn't overwrite the settings file.
+        let settings = ManuallyDrop::new(GraphicsSettings::default());
+        let data = ron::ser::to_string_pretty(&*settings, PrettyConfig::new()).unwrap();
+        let loaded = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data).unwrap());
+
+        assert_eq!(settings.shadow_bias, ShadowDetail::Medium.shadow_bias());
+        assert_eq!(loaded.shadow_bias, settings.shadow_bias);
+
+        let start = data.find("shadow_bias:").unwrap();
+        let end = start + data[start..].find("),").unwrap() + 2;
+        let missing = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data.replace(&data[start..end], "")).unwrap());
+
+        assert_eq!(missing.shadow_bias, ShadowBias::default());
+
+        for shadow_detail in [ShadowDetail::Low, ShadowDetail::Medium, ShadowDetail::High, ShadowDetail::Ultra] {
+            let shadow_bias = shadow_detail.shadow_bias();
+            let data = ron::to_string(&shadow_bias).unwrap();
+
+            assert_eq!(ron::from_str::<ShadowBias>(&data).unwrap(), shadow_bias);
+        }
+    }
+
+    #[test]
+    fn view_distance_round_trip() {
+        // Graphics settings are saved when dropped, so we need to make sure that
+        // the test doesn't overwrite the settings file.
+        let settings = ManuallyDrop::new(GraphicsSettings::default());
+        let data = ron::ser::to_string_pretty(&*settings, PrettyConfig::new()).unwrap();
+        let loaded = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data).unwrap());
+
+        assert_eq!(settings.view_distance, ViewDistance::Unlimited);
+        assert_eq!(loaded.view_distance, settings.view_distance);
+
+        let data = data.replace("view_distance: Unlimited,", "view_distance: Near,");
+        let loaded = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data).unwrap());
+        let missing = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data.replace("view_distance: Near,", "")).unwrap());
+
+        assert_eq!(loaded.view_distance, ViewDistance::Near);
+        assert_eq!(missing.view_distance, ViewDistance::Unlimited);
+    }
+
+    #[test]
+    fn gamma_and_brightness() {
+        let settings = ManuallyDrop::new(GraphicsSettings::default());
+
+        assert_eq!(settings.gamma, 1.0);
+        assert_eq!(settings.brightness, 1.0);
+
+        let data = ron::ser::to_string_pretty(&*settings, PrettyConfig::new())
+            .unwrap()
+            .replace("gamma: 1.0", "gamma: 10.0")
+            .replace("brightness: 1.0", "brightness: -3.0");
+        let loaded = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data).unwrap());
+
+        assert_eq!(loaded.gamma, 2.5);
+        assert_eq!(loaded.brightness, 0.5);
+
+        let missing = data.replace("gamma: 10.0,", "").replace("brightness: -3.0,", "");
+        let loaded = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&missing).unwrap());
+
+        assert_eq!(loaded.gamma, 1.0);
+        assert_eq!(loaded.brightness, 1.0);
+    }
+
+    #[test]
+    fn clamp_field_of_view() {
+        let settings = ManuallyDrop::new(GraphicsSettings::default());
+        let data = ron::ser::to_string_pretty(&*settings, PrettyConfig::new()).unwrap();
+
+        let too_wide =
+            ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data.replace("field_of_view: 15.5", "field_of_view: 170.0")).unwrap());
+        let too_narrow =
+            ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data.replace("field_of_view: 15.5", "field_of_view: 1.0")).unwrap());
+        let missing = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data.replace("field_of_view: 15.5,", "")).unwrap());
+
+        assert_eq!(too_wide.field_of_view, 45.0);
+        assert_eq!(too_narrow.field_of_view, 10.0);
+        assert_eq!(missing.field_of_view, 15.5);
+    }
+
+    #[test]
+    fn wind_affinity_threshold() {
+        let settings = ManuallyDrop::new(GraphicsSettings::default());
+        let data = ron::ser::to_string_pretty(&*settings, PrettyConfig::new()).unwrap();
+        let loaded = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data).unwrap());
+
+        assert_eq!(settings.wind_affinity_threshold, 0.5);
+        assert_eq!(loaded.wind_affinity_threshold, settings.wind_affinity_threshold);
+
+        let too_high = data.replace("wind_affinity_threshold: 0.5", "wind_affinity_threshold: 3.0");
+        let too_high = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&too_high).unwrap());
+        let missing = ManuallyDrop::new(ron::from_str::<GraphicsSettings>(&data.replace("wind_affinity_threshold: 0.5,", "")).unwrap());
+
+        assert_eq!(too_high.wind_affinity_threshold, 1.0);
+        assert_eq!(missing.wind_affinity_threshold, 0.5);
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let path = std::env::temp_dir().join(format!("korangar-missing-graphics-settings-{}.ron", std::process::id()));
+
+        assert!(matches!(GraphicsSettings::load_from(&path), Err(SettingsError::NotFound)));
+    }
+
+    #[test]
+    fn load_malformed_file() {
+        let path = std::env::temp_dir().join(format!("korangar-malformed-graphics-settings-{}.ron", std::process::id()));
+        std::fs::write(&path, "(lighting_mode: Enhanced, present_mode: ").unwrap();
+
+        let result = GraphicsSettings::load_from(&path).map(ManuallyDrop::new);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(SettingsError::Parse(_))));
+    }
+
+    #[test]
+    fn load_legacy_settings() {
+        // Settings file written before the present mode, shadow bias, view distance,
+        // gamma, brightness, field of view and wind affinity threshold were added.
+        let legacy_data = r#"(
+    lighting_mode: Classic,
+    vsync: false,
+    limit_framerate: Limit(60),
+    triple_buffering: true,
+    texture_filtering: Anisotropic(4),
+    msaa: X4,
+    ssaa: Off,
+    screen_space_anti_aliasing: Off,
+    shadow_method: SoftPCSS,
+    shadow_resolution: Normal,
+    shadow_detail: High,
+    sdsm: true,
+    high_quality_interface: true,
+)"#;
+
+        let loaded = ManuallyDrop::new(GraphicsSettings::from_ron(legacy_data).unwrap());
+        let default = ManuallyDrop::new(GraphicsSettings::default());
+
+        assert!(loaded.lighting_mode == LightingMode::Classic);
+        assert_eq!(loaded.limit_framerate, LimitFramerate::Limit(60));
+        assert_eq!(loaded.shadow_detail, ShadowDetail::High);
+        assert_eq!(loaded.present_mode, PresentMode::Mailbox);
+        assert_eq!(loaded.vsync, None);
+        assert_eq!(loaded.shadow_bias, default.shadow_bias);
+        assert_eq!(loaded.view_distance, default.view_distance);
+        assert_eq!(loaded.gamma, default.gamma);
+        assert_eq!(loaded.brightness, default.brightness);
+        assert_eq!(loaded.field_of_view, default.field_of_view);
+        assert_eq!(loaded.wind_affinity_threshold, default.wind_affinity_threshold);
+    }
+
+    #[test]
+    fn migrate_vsync() {
+        let settings = ManuallyDrop::new(GraphicsSettings::default());
+        let data = ron::ser::to_string_pretty(&*settings, PrettyConfig::new()).unwrap();
+
+        assert!(!data.contains("vsync"));
+
+        let loaded = ManuallyDrop::new(GraphicsSettings::from_ron(&data).unwrap());
+        assert_eq!(loaded.present_mode, PresentMode::Fifo);
+
+        for (vsync, present_mode) in [(false, PresentMode::Mailbox), (true, PresentMode::Fifo)] {
+            let legacy_data = data.replace("present_mode: Fifo,", &format!("vsync: {vsync},"));
+            let loaded = ManuallyDrop::new(GraphicsSettings::from_ron(&legacy_data).unwrap());
+
+            assert_eq!(loaded.present_mode, present_mode);
+            assert_eq!(loaded.vsync, None);
+        }
     }
 }
@@ -1,10 +1,10 @@
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, print_debug};
 use korangar_interface::element::StateElement;
-use ron::ser::PrettyConfig;
 use rust_state::RustState;
 use serde::{Deserialize, Serialize};
 
+use super::save_settings_file;
 use crate::loaders::Scaling;
 use crate::state::localization::Language;
 
@@ -17,29 +17,55 @@ pub const MENU_THEMES_PATH: &str = "client/menu_themes";
 pub const IN_GAME_THEMES_PATH: &str = "client/in_game_themes";
 pub const WORLD_THEMES_PATH: &str = "client/world_themes";
 
+/// Every field has a serde default so that adding a field doesn't discard the
+/// rest of the settings stored in an older file.
 #[derive(Clone, Serialize, Deserialize, RustState, StateElement)]
 pub struct InterfaceSettings {
+    /// Version of the format the settings were saved in. Files from before
+    /// the settings were versioned are version 0.
+    #[serde(default)]
+    #[hidden_element]
+    version: u32,
+    #[serde(default = "default_language")]
     pub language: Language,
+    #[serde(default = "default_scaling")]
     pub scaling: Scaling,
+    #[serde(default = "default_theme")]
     pub menu_theme: String,
+    #[serde(default = "default_theme")]
     pub in_game_theme: String,
+    #[serde(default = "default_theme")]
     pub world_theme: String,
 }
 
+fn default_language() -> Language {
+    Language::English
+}
+
+fn default_scaling() -> Scaling {
+    Scaling::new(1.0)
+}
+
+fn default_theme() -> String {
+    DEFAULT_THEME_NAME.to_string()
+}
+
 impl Default for InterfaceSettings {
     fn default() -> Self {
         Self {
-            language: Language::English,
-            scaling: Scaling::new(1.0),
-            menu_theme: DEFAULT_THEME_NAME.to_string(),
-            in_game_theme: DEFAULT_THEME_NAME.to_string(),
-            world_theme: DEFAULT_THEME_NAME.to_string(),
+            version: Self::VERSION,
+            language: default_language(),
+            scaling: default_scaling(),
+            menu_theme: default_theme(),
+            in_game_theme: default_theme(),
+            world_theme: default_theme(),
         }
     }
 }
 
 impl InterfaceSettings {
     const FILE_NAME: &'static str = "client/interface_settings.ron";
+    const VERSION: u32 = 1;
 
     pub fn new() -> Self {
         Self::load().unwrap_or_else(|| {
@@ -54,18 +80,52 @@ impl InterfaceSettings {
         #[cfg(feature = "debug")]
         print_debug!("loading interface settings from {}", Self::FILE_NAME.magenta());
 
-        std::fs::read_to_string(Self::FILE_NAME)
-            .ok()
-            .and_then(|data| ron::from_str(&data).ok())
+        std::fs::read_to_string(Self::FILE_NAME).ok().and_then(|data| Self::from_ron(&data))
+    }
+
+    fn from_ron(data: &str) -> Option<Self> {
+        let mut settings: Self = ron::from_str(data)
+            .inspect_err(|_error| {
+                #[cfg(feature = "debug")]
+                print_debug!("failed to parse interface settings: {:?}", _error.red());
+            })
+            .ok()?;
+
+        settings.migrate();
+
+        Some(settings)
     }
 
-    pub fn save(&self) {
+    /// Update settings saved in an older version of the format. Fields that
+    /// were added are already defaulted during deserialization, so this only
+    /// needs to handle fields that were renamed or changed their meaning.
+    fn migrate(&mut self) {
+        if self.version >= Self::VERSION {
+            return;
+        }
+
+        #[cfg(feature = "debug")]
+        print_debug!(
+            "migrating interface settings from version {} to version {}",
+            self.version.magenta(),
+            Self::VERSION.magenta()
+        );
+
+        // Version 0 only differs by not having a version field.
+        self.version = Self::VERSION;
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
         #[cfg(feature = "debug")]
         print_debug!("saving interface settings to {}", Self::FILE_NAME.magenta());
 
-        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+        save_settings_file(Self::FILE_NAME, self)
+    }
+}
 
-        if let Err(_error) = std::fs::write(Self::FILE_NAME, data) {
+impl Drop for InterfaceSettings {
+    fn drop(&mut self) {
+        if let Err(_error) = self.save() {
             #[cfg(feature = "debug")]
             print_debug!(
                 "failed to save interface settings to {}: {:?}",
@@ -76,12 +136,6 @@ impl InterfaceSettings {
     }
 }
 
-impl Drop for InterfaceSettings {
-    fn drop(&mut self) {
-        self.save();
-    }
-}
-
 #[derive(RustState, StateElement)]
 pub struct InterfaceSettingsCapabilities {
     languages: Vec<Language>,
@@ -139,3 +193,32 @@ impl Default for InterfaceSettingsCapabilities {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::mem::ManuallyDrop;
+
+    use super::{DEFAULT_THEME_NAME, InterfaceSettings};
+    use crate::loaders::Scaling;
+    use crate::state::localization::Language;
+
+    #[test]
+    fn load_unversioned_settings() {
+        // Settings are saved when dropped, so we need to make sure that the test
+        // doesn't overwrite the settings file.
+        let data = r#"(
+            language: German,
+            scaling: 1.5,
+            menu_theme: "menu",
+            in_game_theme: "in_game",
+        )"#;
+        let settings = ManuallyDrop::new(InterfaceSettings::from_ron(data).unwrap());
+
+        assert_eq!(settings.version, InterfaceSettings::VERSION);
+        assert!(settings.language == Language::German);
+        assert_eq!(settings.scaling, Scaling::new(1.5));
+        assert_eq!(settings.menu_theme, "menu");
+        assert_eq!(settings.in_game_theme, "in_game");
+        assert_eq!(settings.world_theme, DEFAULT_THEME_NAME);
+    }
+}
@@ -3,11 +3,11 @@ use std::collections::HashMap;
 #[cfg(feature = "debug")]
 use korangar_debug::logging::{Colorize, print_debug};
 use korangar_interface::element::StateElement;
-use ron::ser::PrettyConfig;
 use rust_state::{MapItem, RustState};
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Serialize, Serializer};
 
+use super::save_settings_file;
 use crate::loaders::ServiceId;
 
 #[derive(Clone, Default, RustState, Serialize, Deserialize, StateElement)]
@@ -73,13 +73,17 @@ impl LoginSettings {
             .and_then(|data| ron::from_str(&data).ok())
     }
 
-    pub fn save(&self) {
+    pub fn save(&self) -> std::io::Result<()> {
         #[cfg(feature = "debug")]
         print_debug!("saving login settings to {}", Self::FILE_NAME.magenta());
 
-        let data = ron::ser::to_string_pretty(self, PrettyConfig::new()).unwrap();
+        save_settings_file(Self::FILE_NAME, self)
+    }
+}
 
-        if let Err(_error) = std::fs::write(Self::FILE_NAME, data) {
+impl Drop for LoginSettings {
+    fn drop(&mut self) {
+        if let Err(_error) = self.save() {
             #[cfg(feature = "debug")]
             print_debug!(
                 "failed to save login settings to {}: {:?}",
@@ -89,9 +93,3 @@ impl LoginSettings {
         }
     }
 }
-
-impl Drop for LoginSettings {
-    fn drop(&mut self) {
-        self.save();
-    }
-}
@@ -5,9 +5,67 @@ mod game;
 mod graphic;
 mod interface;
 mod login;
+mod window;
+
+use std::path::Path;
 
 pub use audio::*;
 pub use game::*;
 pub use graphic::*;
 pub use interface::*;
 pub use login::*;
+use ron::ser::PrettyConfig;
+use serde::Serialize;
+pub use window::*;
+
+/// Error returned when loading a settings file.
+#[derive(Debug)]
+pub enum SettingsError {
+    /// The settings file doesn't exist yet.
+    NotFound,
+    /// The settings file exists but couldn't be read.
+    Io(std::io::Error),
+    /// The settings file isn't valid RON or doesn't match the settings.
+    Parse(ron::error::SpannedError),
+}
+
+/// Read the contents of a settings file, distinguishing a missing file from
+/// other I/O errors.
+fn read_settings_file(path: impl AsRef<Path>) -> Result<String, SettingsError> {
+    std::fs::read_to_string(path).map_err(|error| match error.kind() {
+        std::io::ErrorKind::NotFound => SettingsError::NotFound,
+        _ => SettingsError::Io(error),
+    })
+}
+
+/// Write settings to a RON file, creating any missing parent directories.
+fn save_settings_file(path: impl AsRef<Path>, settings: &impl Serialize) -> std::io::Result<()> {
+    let path = path.as_ref();
+    let data = ron::ser::to_string_pretty(settings, PrettyConfig::new()).map_err(std::io::Error::other)?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(path, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::save_settings_file;
+
+    #[test]
+    fn save_to_missing_directory() {
+        let directory = std::env::temp_dir().join(format!("korangar-settings-{}", std::process::id()));
+        let path = directory.join("nested").join("settings.ron");
+
+        assert!(!directory.exists());
+
+        save_settings_file(&path, &(true, 5)).unwrap();
+
+        let data = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(ron::from_str::<(bool, u32)>(&data).unwrap(), (true, 5));
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
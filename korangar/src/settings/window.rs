@@ -0,0 +1,175 @@
+#[cfg(feature = "debug")]
+use korangar_debug::logging::{Colorize, print_debug};
+use serde::{Deserialize, Serialize};
+
+use super::save_settings_file;
+use crate::INITIAL_SCREEN_SIZE;
+
+/// Position and size of a monitor in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    /// Number of physical pixels per logical pixel.
+    pub scale_factor: f64,
+}
+
+/// Geometry of the client window from the last time it was closed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowSettings {
+    /// Inner width of the window in logical pixels.
+    pub width: u32,
+    /// Inner height of the window in logical pixels.
+    pub height: u32,
+    /// Outer position of the window in physical pixels. [`None`] lets the
+    /// window manager decide.
+    pub position: Option<(i32, i32)>,
+    pub maximized: bool,
+    /// Index of the monitor the window was on, in the order reported by the
+    /// event loop.
+    pub monitor_index: usize,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            width: INITIAL_SCREEN_SIZE.width as u32,
+            height: INITIAL_SCREEN_SIZE.height as u32,
+            position: None,
+            maximized: false,
+            monitor_index: 0,
+        }
+    }
+}
+
+impl WindowSettings {
+    const FILE_NAME: &'static str = "client/window_settings.ron";
+
+    pub fn new() -> Self {
+        Self::load().unwrap_or_else(|| {
+            #[cfg(feature = "debug")]
+            print_debug!("failed to load window settings from {}", Self::FILE_NAME.magenta());
+            Default::default()
+        })
+    }
+
+    pub fn load() -> Option<Self> {
+        #[cfg(feature = "debug")]
+        print_debug!("loading window settings from {}", Self::FILE_NAME.magenta());
+        std::fs::read_to_string(Self::FILE_NAME)
+            .ok()
+            .and_then(|data| ron::from_str(&data).ok())
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        #[cfg(feature = "debug")]
+        print_debug!("saving window settings to {}", Self::FILE_NAME.magenta());
+
+        save_settings_file(Self::FILE_NAME, self)
+    }
+
+    /// The saved position of the window, but only if the window still
+    /// overlaps the monitor it was saved on. Otherwise the window could be
+    /// placed on a monitor that is no longer attached.
+    pub fn validated_position(&self, monitors: &[MonitorBounds]) -> Option<(i32, i32)> {
+        let (x, y) = self.position?;
+        let monitor = monitors.get(self.monitor_index)?;
+
+        // The position is in physical pixels but the size is in logical pixels, so
+        // the size needs to be scaled before the two can be compared.
+        let width = (self.width as f64 * monitor.scale_factor).round() as i32;
+        let height = (self.height as f64 * monitor.scale_factor).round() as i32;
+
+        let overlaps_horizontally = x < monitor.x + monitor.width as i32 && x + width > monitor.x;
+        let overlaps_vertically = y < monitor.y + monitor.height as i32 && y + height > monitor.y;
+
+        (overlaps_horizontally && overlaps_vertically).then_some((x, y))
+    }
+}
+
+impl Drop for WindowSettings {
+    fn drop(&mut self) {
+        if let Err(_error) = self.save() {
+            #[cfg(feature = "debug")]
+            print_debug!(
+                "failed to save window settings to {}: {:?}",
+                Self::FILE_NAME.magenta(),
+                _error.red()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::ManuallyDrop;
+
+    use super::{MonitorBounds, WindowSettings};
+
+    const MONITORS: [MonitorBounds; 2] = [
+        MonitorBounds {
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            scale_factor: 1.0,
+        },
+        MonitorBounds {
+            x: 1920,
+            y: 0,
+            width: 2560,
+            height: 1440,
+            scale_factor: 2.0,
+        },
+    ];
+
+    #[test]
+    fn default_round_trip() {
+        // Settings are saved when dropped, so we need to make sure that the test
+        // doesn't overwrite the settings file.
+        let settings = ManuallyDrop::new(WindowSettings::default());
+        let data = ron::to_string(&*settings).unwrap();
+        let loaded = ManuallyDrop::new(ron::from_str::<WindowSettings>(&data).unwrap());
+
+        assert_eq!(*loaded, *settings);
+    }
+
+    #[test]
+    fn position_on_monitor() {
+        let mut settings = ManuallyDrop::new(WindowSettings::default());
+        settings.position = Some((2000, 100));
+        settings.monitor_index = 1;
+
+        assert_eq!(settings.validated_position(&MONITORS), Some((2000, 100)));
+    }
+
+    #[test]
+    fn reject_off_screen_position() {
+        let mut off_screen = ManuallyDrop::new(WindowSettings::default());
+        off_screen.position = Some((-5000, -5000));
+
+        let mut missing_monitor = ManuallyDrop::new(WindowSettings::default());
+        missing_monitor.position = Some((2000, 100));
+        missing_monitor.monitor_index = 2;
+
+        assert_eq!(off_screen.validated_position(&MONITORS), None);
+        assert_eq!(missing_monitor.validated_position(&MONITORS), None);
+    }
+
+    #[test]
+    fn scale_size_to_physical_pixels() {
+        // The default width of 1280 logical pixels is 2560 physical pixels on the
+        // second monitor, so the right edge of the window still reaches onto it.
+        let mut settings = ManuallyDrop::new(WindowSettings::default());
+        settings.position = Some((-80, 100));
+        settings.monitor_index = 1;
+
+        assert_eq!(settings.validated_position(&MONITORS), Some((-80, 100)));
+
+        settings.position = Some((-700, 100));
+
+        assert_eq!(settings.validated_position(&MONITORS), None);
+    }
+}
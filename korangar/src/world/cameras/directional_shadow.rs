@@ -10,6 +10,9 @@ const LOOK_UP: Vector3<f32> = Vector3::new(0.0, 1.0, 0.0);
 const CAMERA_NEAR_PLANE: f32 = 0.1;
 const CAMERA_FAR_PLANE: f32 = 1000.0;
 
+/// Depth at which the first PSSM cascade starts.
+const PSSM_NEAR_DEPTH: f32 = 100.0;
+
 /// Safety multiplier for the planes of the partitions. Needed to get some
 /// safety margin while the camera moves. This combats missing shadows.
 const SAFETY_MARGIN: f32 = 1.1;
@@ -22,6 +25,7 @@ pub struct DirectionalShadowCamera {
     projection_matrix: Matrix4<f32>,
     view_projection_matrix: Matrix4<f32>,
     near_plane: f32,
+    cascade_count: usize,
     partition: [DirectionalLightPartitionInstruction; PARTITION_COUNT],
 }
 
@@ -35,6 +39,7 @@ impl DirectionalShadowCamera {
             projection_matrix: Matrix4::identity(),
             view_projection_matrix: Matrix4::identity(),
             near_plane: 0.0,
+            cascade_count: PARTITION_COUNT,
             partition: [DirectionalLightPartitionInstruction::default(); PARTITION_COUNT],
         }
     }
@@ -195,6 +200,8 @@ impl DirectionalShadowCamera {
     ) {
         let light_view_inverse = self.update_main_light_camera(direction_to_light, main_camera_view, main_camera_projection);
 
+        self.cascade_count = PARTITION_COUNT;
+
         let projection_inverse = self
             .projection_matrix
             .inverse_transform()
@@ -253,35 +260,50 @@ impl DirectionalShadowCamera {
     /// PSSM partitioning scheme: Blend between logarithmic and uniform
     /// distribution. GPU Gems 3: Parallel-Split Shadow Maps on Programmable
     /// GPUs (2008).
-    fn pssm_partition_from_range(partition_index: usize, min_z: f32, max_z: f32) -> f32 {
+    fn pssm_partition_from_range(partition_index: usize, partition_count: usize, min_z: f32, max_z: f32) -> f32 {
         const BLEND_FACTOR: f32 = 0.5;
         let ratio = max_z / min_z;
-        let power = partition_index as f32 / PARTITION_COUNT as f32;
+        let power = partition_index as f32 / partition_count as f32;
         let log_split = min_z * ratio.powf(power);
-        let uniform_split = min_z + (max_z - min_z) * (partition_index as f32 / PARTITION_COUNT as f32);
+        let uniform_split = min_z + (max_z - min_z) * (partition_index as f32 / partition_count as f32);
         lerp(uniform_split, log_split, BLEND_FACTOR)
     }
 
+    /// The far depth of every PSSM cascade. The last cascade always ends at
+    /// the far plane of the camera.
+    pub fn cascade_splits(cascade_count: usize) -> impl Iterator<Item = f32> {
+        (1..=cascade_count)
+            .map(move |partition_index| Self::pssm_partition_from_range(partition_index, cascade_count, PSSM_NEAR_DEPTH, CAMERA_FAR_PLANE))
+    }
+
+    /// The number of partitions that are used for the current frame. The
+    /// remaining partitions don't need to be rendered.
+    pub fn cascade_count(&self) -> usize {
+        self.cascade_count
+    }
+
     /// Updates the shadow cameras using with the bounds provided by the PSSM
-    /// algorithm.
+    /// algorithm. Only the first `cascade_count` partitions are updated.
     pub fn update_camera_pssm(
         &mut self,
         direction_to_light: Vector3<f32>,
         main_camera_view: &Matrix4<f32>,
         main_camera_projection: &Matrix4<f32>,
         shadow_map_size: u32,
+        cascade_count: usize,
     ) {
         let light_view_inverse = self.update_main_light_camera(direction_to_light, main_camera_view, main_camera_projection);
 
-        let mut near_depth = 100.0;
+        self.cascade_count = cascade_count.clamp(1, PARTITION_COUNT);
+
+        let mut near_depth = PSSM_NEAR_DEPTH;
 
         let camera_view_inverse = main_camera_view
             .inverse_transform()
             .expect("Camera view matrix should be invertible");
 
-        for partition_index in 0..PARTITION_COUNT {
+        for (partition_index, far_depth) in Self::cascade_splits(self.cascade_count).enumerate() {
             let mut light_view = self.view_matrix;
-            let far_depth = Self::pssm_partition_from_range(partition_index, 100.0, CAMERA_FAR_PLANE);
 
             let (min, max) =
                 Self::compute_frustum_extents(&camera_view_inverse, main_camera_projection, &light_view, near_depth, far_depth);
@@ -316,7 +338,12 @@ impl DirectionalShadowCamera {
                 view_projection_matrix: light_projection * light_view,
                 projection_matrix: light_projection,
                 view_matrix: light_view,
-                interval_end: far_depth,
+                // The shader picks the first partition that ends after the fragment, so
+                // the last cascade has to cover everything behind it.
+                interval_end: match partition_index + 1 == self.cascade_count {
+                    true => f32::MAX,
+                    false => far_depth,
+                },
                 world_space_texel_size: (radius * 2.0) / shadow_map_size as f32,
                 near_plane,
                 far_plane,
@@ -404,3 +431,21 @@ impl Camera for PartitionCamera {
         self.view_direction
     }
 }
+
+#[cfg(test)]
+mod cascades {
+    use super::DirectionalShadowCamera;
+    use crate::graphics::ShadowDetail;
+
+    #[test]
+    fn splits_increase() {
+        for shadow_detail in [ShadowDetail::Low, ShadowDetail::Medium, ShadowDetail::High, ShadowDetail::Ultra] {
+            let cascade_count = shadow_detail.cascade_count();
+            let splits: Vec<f32> = DirectionalShadowCamera::cascade_splits(cascade_count).collect();
+
+            assert_eq!(splits.len(), cascade_count);
+            assert!(splits.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!((splits[cascade_count - 1] - super::CAMERA_FAR_PLANE).abs() < 0.01);
+        }
+    }
+}
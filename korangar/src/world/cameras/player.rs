@@ -8,7 +8,7 @@ const ROTATION_SPEED: f32 = 0.005;
 const DEFAULT_DISTANCE: f32 = 400.0;
 const DEFAULT_ANGLE: f32 = 180_f32.to_radians();
 const CAMERA_PITCH: Deg<f32> = Deg(-55.0);
-const VERTICAL_FOV: Deg<f32> = Deg(15.5);
+const DEFAULT_VERTICAL_FOV: Deg<f32> = Deg(15.5);
 const THRESHOLD: f32 = 0.01;
 const LOOK_UP: Vector3<f32> = Vector3::new(0.0, 1.0, 0.0);
 
@@ -21,6 +21,7 @@ pub struct PlayerCamera {
     view_direction: Vector3<f32>,
     view_angle: SmoothedValue,
     camera_distance: SmoothedValue,
+    vertical_fov: Deg<f32>,
     view_matrix: Matrix4<f32>,
     projection_matrix: Matrix4<f32>,
     view_projection_matrix: Matrix4<f32>,
@@ -34,6 +35,7 @@ impl PlayerCamera {
             view_direction: Vector3::zero(),
             view_angle: SmoothedValue::new(DEFAULT_ANGLE, THRESHOLD, 15.0),
             camera_distance: SmoothedValue::new(DEFAULT_DISTANCE, THRESHOLD, 5.0),
+            vertical_fov: DEFAULT_VERTICAL_FOV,
             view_matrix: Matrix4::zero(),
             projection_matrix: Matrix4::zero(),
             view_projection_matrix: Matrix4::zero(),
@@ -52,6 +54,10 @@ impl PlayerCamera {
         self.focus_point.z.set_desired(position.z);
     }
 
+    pub fn set_vertical_fov(&mut self, vertical_fov: Deg<f32>) {
+        self.vertical_fov = vertical_fov;
+    }
+
     pub fn soft_zoom(&mut self, zoom_factor: f32) {
         self.camera_distance
             .move_desired_clamp(zoom_factor * ZOOM_SPEED, MINIMUM_CAMERA_DISTANCE, MAXIMUM_CAMERA_DISTANCE);
@@ -105,7 +111,7 @@ impl Camera for PlayerCamera {
     fn generate_view_projection(&mut self, window_size: Vector2<usize>) {
         let aspect_ratio = window_size.x as f32 / window_size.y as f32;
         self.view_matrix = Matrix4::look_to_lh(self.camera_position, self.view_direction, LOOK_UP);
-        self.projection_matrix = perspective_reverse_lh(VERTICAL_FOV, aspect_ratio);
+        self.projection_matrix = perspective_reverse_lh(self.vertical_fov, aspect_ratio);
         self.view_projection_matrix = self.projection_matrix * self.view_matrix;
     }
 
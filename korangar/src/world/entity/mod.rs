@@ -517,7 +517,7 @@ impl Common {
                 let last_step_position = Vector2::new(last_step.arrival_position.x as isize, last_step.arrival_position.y as isize);
                 let next_step_position = Vector2::new(next_step.arrival_position.x as isize, next_step.arrival_position.y as isize);
 
-                let array = last_step_position - next_step_position;
+                let array = next_step_position - last_step_position;
                 let array: &[isize; 2] = array.as_ref();
                 self.direction = (*array).try_into().unwrap();
 
@@ -1317,8 +1317,8 @@ impl Entity {
         // FIX: This check is a little bit broken. This will prefer rotation diagonally
         // over rotating straight.
         if let Ok(direction) = Direction::try_from([
-            (common.tile_position.x as isize - target_position.x as isize).clamp(-1, 1),
-            (common.tile_position.y as isize - target_position.y as isize).clamp(-1, 1),
+            (target_position.x as isize - common.tile_position.x as isize).clamp(-1, 1),
+            (target_position.y as isize - common.tile_position.y as isize).clamp(-1, 1),
         ]) {
             common.direction = direction;
         }
@@ -31,7 +31,9 @@ use super::{LightSourceExt, Model, PointLightSet};
 use crate::graphics::{
     DebugAabbInstruction, DebugCircleInstruction, DebugRectangleInstruction, ModelBatch, RenderOptions, ScreenPosition, ScreenSize,
 };
-use crate::graphics::{EntityInstruction, IndicatorInstruction, ModelInstruction, Texture, TextureSet, WaterInstruction, WaterVertex};
+use crate::graphics::{
+    EntityInstruction, IndicatorInstruction, ModelInstruction, Texture, TextureSet, ViewDistance, WaterInstruction, WaterVertex,
+};
 use crate::loaders::GAT_TILE_SIZE;
 #[cfg(feature = "debug")]
 use crate::renderer::MarkerRenderer;
@@ -307,6 +309,7 @@ impl Map {
     pub fn cull_objects_with_frustum<'a>(
         &'a self,
         camera: &dyn Camera,
+        view_distance: ViewDistance,
         object_set: &'a mut ResourceSetBuffer<ObjectKey>,
         #[cfg(feature = "debug")] enabled: bool,
     ) -> ResourceSet<'a, ObjectKey> {
@@ -317,7 +320,13 @@ impl Map {
             });
         }
 
-        let frustum = Frustum::new(camera.view_projection_matrix(), true);
+        // The projection of our cameras has an infinite far plane, so we limit the
+        // view distance when culling instead.
+        let mut frustum = Frustum::new(camera.view_projection_matrix(), true);
+
+        if let Some(tiles) = view_distance.tiles() {
+            frustum = frustum.with_far_plane(tiles as f32 * GAT_TILE_SIZE);
+        }
 
         object_set.create_set(|visible_objects| {
             self.object_kdtree.query(&frustum, visible_objects);
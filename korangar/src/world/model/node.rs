@@ -1,4 +1,5 @@
 use cgmath::{Matrix, Matrix4, Point3, SquareMatrix, Transform as PointTransform, Vector4, VectorSpace};
+use korangar_collision::AABB;
 use korangar_interface::element::StateElement;
 use ragnarok_formats::model::{RotationKeyframeData, ScaleKeyframeData, TranslationKeyframeData};
 use ragnarok_formats::version::InternalVersion;
@@ -20,6 +21,9 @@ pub struct Node {
     pub position: Vector4<f32>,
     #[hidden_element]
     pub centroid: Point3<f32>,
+    /// Bounding box of the vertices of this node, in the same space as the
+    /// bounding box of the [`Model`](super::Model).
+    pub bounding_box: AABB,
     pub sub_meshes: Vec<SubMesh>,
     // TODO: Unhide once this doesn't crash the compilation anymore.
     #[hidden_element]
@@ -38,6 +42,7 @@ impl Node {
         parent_rotation_matrix: Matrix4<f32>,
         position: Vector4<f32>,
         centroid: Point3<f32>,
+        bounding_box: AABB,
         sub_meshes: Vec<SubMesh>,
         child_nodes: Vec<Node>,
         animation_length: u32,
@@ -52,6 +57,7 @@ impl Node {
             parent_rotation_matrix,
             position,
             centroid,
+            bounding_box,
             sub_meshes,
             child_nodes,
             animation_length,
@@ -296,3 +302,68 @@ impl Node {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cgmath::{Matrix4, Point3, SquareMatrix, Vector3, Vector4, Zero};
+    use korangar_collision::AABB;
+    use ragnarok_formats::model::ScaleKeyframeData;
+    use ragnarok_formats::version::InternalVersion;
+
+    use super::Node;
+
+    fn node_with_scale_keyframes(scale_keyframes: Vec<ScaleKeyframeData>) -> Node {
+        Node::new(
+            InternalVersion { major: 1, minor: 5 },
+            Matrix4::identity(),
+            Matrix4::identity(),
+            Matrix4::identity(),
+            Vector4::zero(),
+            Point3::new(0.0, 0.0, 0.0),
+            AABB::uninitialized(),
+            Vec::new(),
+            Vec::new(),
+            2000,
+            scale_keyframes,
+            Vec::new(),
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn scale_keyframes_interpolate() {
+        let node = node_with_scale_keyframes(vec![
+            ScaleKeyframeData::new(0, Vector3::new(1.0, 1.0, 1.0), 0.0),
+            ScaleKeyframeData::new(1000, Vector3::new(3.0, 5.0, 1.0), 0.0),
+        ]);
+
+        assert_eq!(node.scale_animation_matrix(0.0), Matrix4::identity());
+        assert_eq!(
+            node.scale_animation_matrix(500.0),
+            Matrix4::from_nonuniform_scale(2.0, 3.0, 1.0)
+        );
+        assert_eq!(
+            node.scale_animation_matrix(1000.0),
+            Matrix4::from_nonuniform_scale(3.0, 5.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn scale_keyframes_applied_to_world_matrix() {
+        let node = node_with_scale_keyframes(vec![
+            ScaleKeyframeData::new(0, Vector3::new(1.0, 1.0, 1.0), 0.0),
+            ScaleKeyframeData::new(1000, Vector3::new(3.0, 3.0, 3.0), 0.0),
+        ]);
+
+        let (model_matrix, ..) = node.world_matrix(
+            500.0,
+            &Matrix4::identity(),
+            &Matrix4::identity(),
+            &Matrix4::identity(),
+            &Vector4::zero(),
+            false,
+        );
+
+        assert_eq!(model_matrix, Matrix4::from_scale(2.0));
+    }
+}
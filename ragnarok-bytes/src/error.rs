@@ -1,9 +1,31 @@
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ConversionErrorType {
-    ByteReaderTooShort { type_name: &'static str },
-    DataTooBig { type_name: &'static str },
-    IncorrectMetadata { type_name: &'static str },
-    Specific { message: String },
+    ByteReaderTooShort {
+        type_name: &'static str,
+    },
+    DataTooBig {
+        type_name: &'static str,
+    },
+    IncorrectMetadata {
+        type_name: &'static str,
+    },
+    MismatchedHeader {
+        expected: u16,
+        actual: u16,
+        offset: usize,
+    },
+    MismatchedPacketLength {
+        expected: u16,
+        actual: usize,
+    },
+    TrailingBytes {
+        type_name: &'static str,
+        element_size: usize,
+        trailing: usize,
+    },
+    Specific {
+        message: String,
+    },
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -57,6 +79,31 @@ impl std::fmt::Debug for ConversionError {
                     type_name, stack
                 )
             }
+            ConversionErrorType::MismatchedHeader { expected, actual, offset } => {
+                write!(
+                    formatter,
+                    "mismatched header: expected 0x{:04X} but found 0x{:04X} at offset {} in {}",
+                    expected, actual, offset, stack
+                )
+            }
+            ConversionErrorType::MismatchedPacketLength { expected, actual } => {
+                write!(
+                    formatter,
+                    "mismatched packet length: expected {} bytes but read {} bytes in {}",
+                    expected, actual, stack
+                )
+            }
+            ConversionErrorType::TrailingBytes {
+                type_name,
+                element_size,
+                trailing,
+            } => {
+                write!(
+                    formatter,
+                    "trailing {} bytes are too few for another element of {} ({} bytes each) in {}",
+                    trailing, type_name, element_size, stack
+                )
+            }
             ConversionErrorType::Specific { message } => write!(formatter, "{} in {}", message, stack),
         }
     }
@@ -0,0 +1,143 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+use crate::{ByteReader, ByteWriter, ConversionError, ConversionErrorType, ConversionResult, FixedByteSize, FromBytes, ToBytes};
+
+/// String that always occupies `SIZE` bytes.
+///
+/// Reading consumes exactly `SIZE` bytes and cuts the string at the first null
+/// byte. Writing pads the string with null bytes. A string that fills all
+/// `SIZE` bytes is written without a null terminator, anything longer fails
+/// with [`ConversionErrorType::DataTooBig`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FixedString<const SIZE: usize>(Box<str>);
+
+impl<const SIZE: usize> FixedString<SIZE> {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into().into_boxed_str())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const SIZE: usize> Deref for FixedString<SIZE> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const SIZE: usize> Display for FixedString<SIZE> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.0)
+    }
+}
+
+impl<const SIZE: usize> From<&str> for FixedString<SIZE> {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const SIZE: usize> From<String> for FixedString<SIZE> {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const SIZE: usize> From<FixedString<SIZE>> for String {
+    fn from(value: FixedString<SIZE>) -> Self {
+        value.0.into_string()
+    }
+}
+
+impl<const SIZE: usize> PartialEq<&str> for FixedString<SIZE> {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl<const SIZE: usize> FixedByteSize for FixedString<SIZE> {
+    fn size_in_bytes() -> usize {
+        SIZE
+    }
+}
+
+impl<const SIZE: usize> FromBytes for FixedString<SIZE> {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        let bytes = byte_reader.slice::<Self>(SIZE)?;
+        let length = bytes.iter().position(|byte| *byte == 0).unwrap_or(SIZE);
+        let bytes = bytes[..length].to_vec();
+
+        Ok(Self::new(byte_reader.decode_string(&bytes)))
+    }
+}
+
+impl<const SIZE: usize> ToBytes for FixedString<SIZE> {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        // The written size includes the null terminator.
+        let written = byte_writer.write_counted(|writer| {
+            writer.encode_string(&self.0);
+            Ok(())
+        })?;
+
+        match written - 1 {
+            length if length > SIZE => Err(ConversionError::from_error_type(ConversionErrorType::DataTooBig {
+                type_name: std::any::type_name::<Self>(),
+            })),
+            length if length == SIZE => {
+                byte_writer.pop();
+                Ok(SIZE)
+            }
+            _ => {
+                byte_writer.extend(SIZE - written, 0);
+                Ok(SIZE)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use crate::{ByteReader, ByteWriter, FixedString, FromBytes, ToBytes};
+
+    #[test]
+    fn exact_fit() {
+        let mut byte_writer = ByteWriter::new();
+
+        assert_eq!(FixedString::<4>::new("abcd").to_bytes(&mut byte_writer).unwrap(), 4);
+
+        let bytes = byte_writer.into_inner();
+        assert_eq!(bytes, b"abcd");
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        assert_eq!(FixedString::<4>::from_bytes(&mut byte_reader).unwrap(), "abcd");
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn short() {
+        let mut byte_writer = ByteWriter::new();
+
+        assert_eq!(FixedString::<6>::new("ab").to_bytes(&mut byte_writer).unwrap(), 6);
+        assert_eq!(byte_writer.into_inner(), [b'a', b'b', 0, 0, 0, 0]);
+
+        // Everything after the first null byte is skipped.
+        let bytes = [b'a', b'b', 0, b'c', 0, 0, 1];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+
+        assert_eq!(FixedString::<6>::from_bytes(&mut byte_reader).unwrap(), "ab");
+        assert_eq!(byte_reader.remaining_bytes(), [1]);
+    }
+
+    #[test]
+    fn overflowing() {
+        let mut byte_writer = ByteWriter::new();
+
+        assert!(FixedString::<4>::new("abcde").to_bytes(&mut byte_writer).is_err());
+        assert!(FixedString::<4>::from_bytes(&mut ByteReader::without_metadata(b"abc")).is_err());
+    }
+}
@@ -3,8 +3,10 @@
 
 mod error;
 mod fixed;
+mod fixed_string;
 mod from_bytes;
 mod reader;
+mod reserved;
 mod to_bytes;
 mod writer;
 
@@ -14,8 +16,10 @@ pub use ragnarok_macros::{ByteConvertable, FixedByteSize, FromBytes, ToBytes};
 
 pub use self::error::{ConversionError, ConversionErrorType, ConversionResult, ConversionResultExt};
 pub use self::fixed::{FixedByteSize, FixedByteSizeCollection};
+pub use self::fixed_string::FixedString;
 pub use self::from_bytes::{FromBytes, FromBytesExt};
 pub use self::reader::ByteReader;
+pub use self::reserved::Reserved;
 pub use self::to_bytes::{ToBytes, ToBytesExt};
 pub use self::writer::ByteWriter;
 
@@ -204,6 +204,23 @@ where
         self.offset = self.limit;
         data
     }
+
+    /// View of the data that is left in the reader. Unlike
+    /// [`remaining_bytes`](ByteReader::remaining_bytes), this neither copies
+    /// nor consumes the data.
+    pub fn subslice_from_here(&self) -> &'a [u8] {
+        &self.data[self.offset..self.limit]
+    }
+
+    /// View of the data consumed since `offset`, which is usually taken from
+    /// [`get_offset`](ByteReader::get_offset) before reading.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is past the current offset of the reader.
+    pub fn consumed_since(&self, offset: usize) -> &'a [u8] {
+        &self.data[offset..self.offset]
+    }
 }
 
 #[cfg(test)]
@@ -399,3 +416,42 @@ mod remaining_bytes {
         assert!(byte_reader.remaining_bytes().is_empty());
     }
 }
+
+#[cfg(test)]
+mod views {
+    use crate::{ByteReader, FromBytes};
+
+    const TEST_BYTES: &[u8] = &[0x34, 0x12, 0xAB, 0xCD, 0xEF];
+
+    #[test]
+    fn header_and_payload() {
+        let mut byte_reader = ByteReader::without_metadata(TEST_BYTES);
+        let start = byte_reader.get_offset();
+
+        assert_eq!(u16::from_bytes(&mut byte_reader).unwrap(), 0x1234);
+
+        let header = byte_reader.consumed_since(start);
+        let payload = byte_reader.subslice_from_here();
+
+        assert_eq!(header, &TEST_BYTES[..2]);
+        assert_eq!(payload, &TEST_BYTES[2..]);
+        assert_eq!(header.as_ptr(), TEST_BYTES.as_ptr());
+        assert_eq!(payload.as_ptr(), TEST_BYTES[2..].as_ptr());
+
+        // Taking the views does not consume anything.
+        assert_eq!(byte_reader.remaining_bytes().as_slice(), payload);
+    }
+
+    #[test]
+    fn respects_limit() {
+        let mut byte_reader = ByteReader::without_metadata(TEST_BYTES);
+        let temporary_limit = byte_reader.install_limit::<()>(3).unwrap();
+
+        assert_eq!(byte_reader.subslice_from_here(), &TEST_BYTES[..3]);
+
+        byte_reader.uninstall_limit(temporary_limit);
+
+        assert_eq!(byte_reader.subslice_from_here(), &TEST_BYTES[3..]);
+        assert_eq!(byte_reader.consumed_since(0), &TEST_BYTES[..3]);
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::{ByteReader, ByteWriter, ConversionResult, ConversionResultExt, FixedByteSize, FromBytes, ToBytes};
+
+/// Reserved or unused bytes inside of a struct. Reading skips `SIZE` bytes,
+/// regardless of their value, and writing produces `SIZE` zeros.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Reserved<const SIZE: usize>;
+
+impl<const SIZE: usize> Debug for Reserved<SIZE> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "Reserved<{SIZE}>")
+    }
+}
+
+impl<const SIZE: usize> FixedByteSize for Reserved<SIZE> {
+    fn size_in_bytes() -> usize {
+        SIZE
+    }
+}
+
+impl<const SIZE: usize> FromBytes for Reserved<SIZE> {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        byte_reader.slice::<Self>(SIZE).map(|_| Self)
+    }
+}
+
+impl<const SIZE: usize> ToBytes for Reserved<SIZE> {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        [0u8; SIZE].to_bytes(byte_writer).trace::<Self>()
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use crate::{ByteReader, ByteWriter, FixedByteSize, FromBytes, Reserved, ToBytes};
+
+    #[test]
+    fn consumes_size() {
+        let bytes = [1, 2, 3, 4, 5];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+
+        assert_eq!(Reserved::<4>::from_bytes(&mut byte_reader).unwrap(), Reserved);
+        assert_eq!(byte_reader.remaining_bytes(), [5]);
+        assert!(Reserved::<2>::from_bytes(&mut ByteReader::without_metadata(&[1])).is_err());
+    }
+
+    #[test]
+    fn writes_zeros() {
+        let mut byte_writer = ByteWriter::new();
+
+        assert_eq!(Reserved::<3>.to_bytes(&mut byte_writer).unwrap(), 3);
+        assert_eq!(byte_writer.into_inner(), [0, 0, 0]);
+        assert_eq!(Reserved::<128>::size_in_bytes(), 128);
+        assert_eq!(format!("{:?}", Reserved::<16>), "Reserved<16>");
+    }
+}
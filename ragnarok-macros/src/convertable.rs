@@ -83,8 +83,22 @@ fn derive_for_enum(
     let mut current_index = 0usize;
     let mut indices = Vec::new();
     let mut values = Vec::new();
+    let mut fallback = None;
 
     for mut variant in data_enum.variants.into_iter() {
+        // The fallback variant holds the raw value of any variant that is not
+        // known, so it does not take up an index.
+        if get_unique_attribute(&mut variant.attrs, "fallback").is_some() {
+            assert!(fallback.is_none(), "only one variant may be marked as fallback");
+            assert!(
+                matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1),
+                "fallback variant must have a single unnamed field"
+            );
+
+            fallback = Some(variant.ident);
+            continue;
+        }
+
         if let Some(attribute) = get_unique_attribute(&mut variant.attrs, "numeric_value") {
             current_index = attribute
                 .parse_args::<syn::LitInt>()
@@ -93,18 +107,45 @@ fn derive_for_enum(
                 .expect("numeric_value failed to parse integer as base 10");
         }
 
+        // An explicit discriminant is used as the value on the wire, so casting the
+        // variant gives the same value.
+        if let Some((_, discriminant)) = &variant.discriminant {
+            let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(discriminant),
+                ..
+            }) = discriminant
+            else {
+                panic!("discriminant requires an integer value");
+            };
+
+            current_index = discriminant
+                .base10_parse()
+                .expect("discriminant failed to parse integer as base 10");
+        }
+
         indices.push(current_index);
         values.push(variant.ident);
         current_index += 1;
     }
 
+    let from_fallback = match &fallback {
+        Some(fallback) => quote!(_ => Ok(Self::#fallback(value)),),
+        None => quote!(invalid => Err(ragnarok_bytes::ConversionError::from_message(format!("invalid enum variant {}", invalid))),),
+    };
+
+    let to_fallback = fallback.as_ref().map(
+        |fallback| quote!(#name::#fallback(value) => ragnarok_bytes::ConversionResultExt::trace::<Self>(value.to_bytes(byte_writer)),),
+    );
+
     let from = add_from.then(|| {
         quote! {
             impl #impl_generics ragnarok_bytes::FromBytes for #name #type_generics #where_clause {
                 fn from_bytes<Meta>(byte_reader: &mut ragnarok_bytes::ByteReader<Meta>) -> ragnarok_bytes::ConversionResult<Self> {
-                    match ragnarok_bytes::ConversionResultExt::trace::<Self>(#numeric_type::from_bytes(byte_reader))? as usize {
+                    let value = ragnarok_bytes::ConversionResultExt::trace::<Self>(#numeric_type::from_bytes(byte_reader))?;
+
+                    match value as usize {
                         #( #indices => Ok(Self::#values), )*
-                        invalid => Err(ragnarok_bytes::ConversionError::from_message(format!("invalid enum variant {}", invalid))),
+                        #from_fallback
                     }
                 }
             }
@@ -119,6 +160,7 @@ fn derive_for_enum(
                 fn to_bytes(&self, byte_writer: &mut ragnarok_bytes::ByteWriter) -> ragnarok_bytes::ConversionResult<usize> {
                     match self {
                         #( #name::#values => ragnarok_bytes::ConversionResultExt::trace::<Self>((#indices as #numeric_type).to_bytes(byte_writer)), )*
+                        #to_fallback
                     }
                 }
             }
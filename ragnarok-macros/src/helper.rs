@@ -53,7 +53,7 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
             None => quote!(ragnarok_bytes::FromBytes::from_bytes(byte_reader)),
         };
 
-        let to_length = match length {
+        let to_length = match length.clone() {
             Some(length) if syn::parse::<syn::Ident>(length.clone().into()).is_ok() => {
                 quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(&self.#field_identifier, writer, self.#length as usize))
             }
@@ -61,6 +61,16 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
             None => quote!(ragnarok_bytes::ToBytes::to_bytes(&self.#field_identifier, writer)),
         };
 
+        // Fields restricted by the packet version are optional, so they are written
+        // from the inner value.
+        let to_length_inner = match length.clone() {
+            Some(length) if syn::parse::<syn::Ident>(length.clone().into()).is_ok() => {
+                quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(#field_variable, writer, self.#length as usize))
+            }
+            Some(length) => quote!(ragnarok_bytes::ToBytesExt::to_n_bytes(#field_variable, writer, #length as usize)),
+            None => quote!(ragnarok_bytes::ToBytes::to_bytes(#field_variable, writer)),
+        };
+
         let mut repeating: Option<(syn::Ident, bool)> = None;
 
         if let Some(identifier) = get_unique_attribute(&mut field.attrs, "repeating").map(|attribute| match attribute.meta {
@@ -93,12 +103,30 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
             .map(|version: Version| (version.major, version.minor))
             .map(|(major, minor)| quote!(equals_or_above(#major, #minor)));
 
+        let packet_version_smaller = get_unique_attribute(&mut field.attrs, "packet_version_smaller")
+            .map(|attribute| attribute.parse_args::<syn::LitInt>().expect("failed to parse packet version"))
+            .map(|version| quote!(smaller(#version)));
+
+        let packet_version_equals_or_above = get_unique_attribute(&mut field.attrs, "packet_version_equals_or_above")
+            .map(|attribute| attribute.parse_args::<syn::LitInt>().expect("failed to parse packet version"))
+            .map(|version| quote!(equals_or_above(#version)));
+
         assert!(
-            version_smaller.is_none() || version_equals_or_above.is_none(),
+            [
+                version_smaller.is_some(),
+                version_equals_or_above.is_some(),
+                packet_version_smaller.is_some(),
+                packet_version_equals_or_above.is_some(),
+            ]
+            .into_iter()
+            .filter(|restricted| *restricted)
+            .count()
+                <= 1,
             "version restriction may only be specified once"
         );
         let version_function = version_smaller.or(version_equals_or_above);
         let version_restricted = version_function.is_some();
+        let packet_version_function = packet_version_smaller.or(packet_version_equals_or_above);
 
         // base from bytes implementation
         let from_implementation = quote!(ragnarok_bytes::ConversionResultExt::trace::<Self>(#from_length)?);
@@ -127,19 +155,36 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
             }
             None if repeating_remaining => {
                 quote!({
-                    let remaining_bytes = __packet_length - ((byte_reader.get_offset() - base_offset) as u16) - 2;
-                    let struct_size = <#field_type as ragnarok_bytes::FixedByteSizeCollection>::size_in_bytes() as u16;
+                    // We add 2 for the header bytes
+                    let remaining_bytes = (__packet_length as usize).saturating_sub(2 + (byte_reader.get_offset() - base_offset));
+                    let mut vector = Vec::new();
+
+                    if remaining_bytes > 0 {
+                        // The size of an element can depend on the packet version, so we take it
+                        // from the first element instead of the type.
+                        let element_offset = byte_reader.get_offset();
+                        vector.push(#from_implementation);
 
-                    if remaining_bytes % struct_size != 0 {
-                        return Err(ragnarok_bytes::ConversionError::from_message("type doesn't perfectly divide remaining data"));
-                    }
+                        let element_size = byte_reader.get_offset() - element_offset;
+                        let trailing_bytes = remaining_bytes.checked_rem(element_size).unwrap_or(remaining_bytes);
+
+                        if trailing_bytes != 0 {
+                            return Err(ragnarok_bytes::ConversionError::from_error_type(
+                                ragnarok_bytes::ConversionErrorType::TrailingBytes {
+                                    type_name: std::any::type_name::<#field_type>(),
+                                    element_size,
+                                    trailing: trailing_bytes,
+                                },
+                            ));
+                        }
 
-                    let repeat_count = (remaining_bytes / struct_size) as usize;
-                    // TODO: Add check to make sure this allocation is not too big.
-                    let mut vector = Vec::with_capacity(repeat_count);
+                        let repeat_count = remaining_bytes / element_size;
+                        // TODO: Add check to make sure this allocation is not too big.
+                        vector.reserve(repeat_count - 1);
 
-                    for _ in 0..repeat_count {
-                        vector.push(#from_implementation);
+                        for _ in 1..repeat_count {
+                            vector.push(#from_implementation);
+                        }
                     }
 
                     vector
@@ -180,13 +225,26 @@ pub fn byte_convertable_helper(data_struct: DataStruct) -> (TokenStream, Vec<Tok
                     };
                 }
             }
-            None => quote!(let #field_variable = #from_implementation;),
+            None => match packet_version_function.as_ref() {
+                Some(function) => quote! {
+                    let #field_variable = match ragnarok_packets::PacketVersion::from_metadata(byte_reader).#function {
+                        true => Some(#from_implementation),
+                        false => None,
+                    };
+                },
+                None => quote!(let #field_variable = #from_implementation;),
+            },
         };
         from_bytes_implementations.push(from_implementation);
 
         // base to byte implementation
         let to_implementation = match version_restricted {
             true => quote!(panic!("version restricted fields can't be serialized at the moment");),
+            false if packet_version_function.is_some() => quote! {
+                if let Some(#field_variable) = &self.#field_identifier {
+                    ragnarok_bytes::ConversionResultExt::trace::<Self>(#to_length_inner)?;
+                }
+            },
             false => quote!(ragnarok_bytes::ConversionResultExt::trace::<Self>(#to_length)?;),
         };
         to_bytes_implementations.push(to_implementation);
@@ -34,12 +34,15 @@ pub fn derive_fixed_byte_size(token_stream: InterfaceTokenStream) -> InterfaceTo
 #[proc_macro_derive(
     ByteConvertable,
     attributes(
+        fallback,
         length,
         new_default,
         new_derive,
         new_value,
         numeric_type,
         numeric_value,
+        packet_version_equals_or_above,
+        packet_version_smaller,
         repeating,
         repeating_expr,
         repeating_option,
@@ -67,9 +70,12 @@ pub fn derive_byte_convertable(token_stream: InterfaceTokenStream) -> InterfaceT
 #[proc_macro_derive(
     FromBytes,
     attributes(
+        fallback,
         length,
         numeric_type,
         numeric_value,
+        packet_version_equals_or_above,
+        packet_version_smaller,
         repeating,
         repeating_expr,
         repeating_option,
@@ -97,12 +103,15 @@ pub fn derive_from_bytes(token_stream: InterfaceTokenStream) -> InterfaceTokenSt
 #[proc_macro_derive(
     ToBytes,
     attributes(
+        fallback,
         length,
         new_default,
         new_derive,
         new_value,
         numeric_type,
         numeric_value,
+        packet_version_equals_or_above,
+        packet_version_smaller,
         version,
         version_equals_or_above,
         version_smaller,
@@ -134,6 +143,8 @@ pub fn derive_to_bytes(token_stream: InterfaceTokenStream) -> InterfaceTokenStre
         new_default,
         new_derive,
         new_value,
+        packet_version_equals_or_above,
+        packet_version_smaller,
         ping,
         repeating,
         repeating_option,
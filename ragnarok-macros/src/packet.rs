@@ -21,6 +21,14 @@ pub fn derive_packet_struct(
     let is_variable_length = get_unique_attribute(&mut attributes, "variable_length").is_some();
 
     let signature = packet_signature.signature;
+    // Some packets are sent with a packet length that is off by one, so we can't
+    // validate the length for them.
+    let is_off_by_one = data_struct.fields.iter().any(|field| {
+        field
+            .attrs
+            .iter()
+            .any(|attribute| attribute.path().is_ident("length_remaining_off_by_one"))
+    });
     let (new_implementation, from_bytes_implementations, implemented_fields, to_bytes_implementations, delimiter) =
         byte_convertable_helper(data_struct);
 
@@ -34,6 +42,20 @@ pub fn derive_packet_struct(
         let __packet_length = ragnarok_bytes::ConversionResultExt::trace::<Self>(u16::from_bytes(byte_reader))?;
     });
 
+    let check_packet_length = (is_variable_length && !is_off_by_one).then_some(quote! {
+        // We add 2 for the header bytes
+        let consumed = byte_reader.get_offset() - base_offset + 2;
+
+        if consumed != __packet_length as usize {
+            return Err(ragnarok_bytes::ConversionError::from_error_type(
+                ragnarok_bytes::ConversionErrorType::MismatchedPacketLength {
+                    expected: __packet_length,
+                    actual: consumed,
+                },
+            ));
+        }
+    });
+
     let final_to_bytes = match is_variable_length {
         _ if to_bytes_implementations.is_empty() => quote! {
             Ok(0)
@@ -77,6 +99,7 @@ pub fn derive_packet_struct(
                 let base_offset = byte_reader.get_offset();
                 #insert_packet_length
                 #(#from_bytes_implementations)*
+                #check_packet_length
                 let packet = #instanciate;
 
                 Ok(packet)
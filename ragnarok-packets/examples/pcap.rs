@@ -287,7 +287,7 @@ fn main() {
         RestartResponsePacket,
         DisconnectResponsePacket,
         UseSkillSuccessPacket,
-        ToUseSkillSuccessPacket,
+        SkillFailedPacket,
         NotifySkillUnitPacket,
         SkillUnitDisappearPacket,
         NotifyGroundSkillPacket,
@@ -0,0 +1,100 @@
+use std::sync::{Arc, PoisonError, RwLock};
+
+use ragnarok_bytes::ConversionError;
+
+use crate::PacketHeader;
+
+type DecodeErrorHook = Arc<dyn Fn(PacketHeader, &[u8], &ConversionError) + Send + Sync>;
+
+static DECODE_ERROR_HOOK: RwLock<Option<DecodeErrorHook>> = RwLock::new(None);
+
+/// Set a function that is called every time
+/// [`PacketExt::packet_from_bytes`](crate::PacketExt::packet_from_bytes) fails
+/// after reading the header. The function receives the header, the raw bytes
+/// starting at the header and the error. This replaces any previous hook.
+///
+/// Packets processed by the [`PacketHandler`](crate::handler::PacketHandler)
+/// are reported through
+/// [`PacketCallback::failed_packet`](crate::handler::PacketCallback::failed_packet)
+/// instead.
+pub fn set_decode_error_hook(hook: impl Fn(PacketHeader, &[u8], &ConversionError) + Send + Sync + 'static) {
+    *DECODE_ERROR_HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(hook));
+}
+
+/// Remove the hook set with [`set_decode_error_hook`].
+pub fn clear_decode_error_hook() {
+    *DECODE_ERROR_HOOK.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+pub(crate) fn report_decode_error(header: PacketHeader, bytes: &[u8], error: &ConversionError) {
+    // Clone the hook out of the lock, so the hook itself is free to set or clear
+    // the hook without deadlocking. A hook that panicked poisons the lock, but
+    // the stored hook is still valid, so we keep using it.
+    let hook = DECODE_ERROR_HOOK.read().unwrap_or_else(PoisonError::into_inner).clone();
+
+    if let Some(hook) = hook {
+        hook(header, bytes, error);
+    }
+}
+
+#[cfg(test)]
+mod decode_error {
+    use std::sync::{Mutex, MutexGuard, PoisonError};
+
+    use ragnarok_bytes::ByteReader;
+
+    use crate::{BroadcastMessagePacket, PacketExt, PacketHeader, clear_decode_error_hook, set_decode_error_hook};
+
+    /// The hook is global, so tests that set it must not run at the same time.
+    static HOOK_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Clears the hook when dropped, so it doesn't leak into other tests, even
+    /// if the test fails.
+    struct HookGuard {
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl HookGuard {
+        fn new() -> Self {
+            Self {
+                _lock: HOOK_LOCK.lock().unwrap_or_else(PoisonError::into_inner),
+            }
+        }
+    }
+
+    impl Drop for HookGuard {
+        fn drop(&mut self) {
+            clear_decode_error_hook();
+        }
+    }
+
+    #[test]
+    fn forced_parse_error() {
+        static REPORTED: Mutex<Vec<(PacketHeader, Vec<u8>)>> = Mutex::new(Vec::new());
+
+        let _guard = HookGuard::new();
+        set_decode_error_hook(|header, bytes, _| REPORTED.lock().unwrap().push((header, bytes.to_vec())));
+
+        // The packet length exceeds the available data.
+        let bytes = [0x9A, 0x00, 0x40, 0x00, b'h', b'i', 0x00];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+
+        assert!(BroadcastMessagePacket::packet_from_bytes(&mut byte_reader).is_err());
+
+        // Other tests might fail to parse packets at the same time, so we only
+        // check that our packet was reported.
+        assert!(REPORTED.lock().unwrap().contains(&(PacketHeader(0x009A), bytes.to_vec())));
+    }
+
+    #[test]
+    fn hook_can_clear_itself() {
+        let _guard = HookGuard::new();
+        set_decode_error_hook(|_, _, _| clear_decode_error_hook());
+
+        let bytes = [0x9A, 0x00, 0x40, 0x00];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+
+        // Would deadlock if the hook was called while holding the lock.
+        assert!(BroadcastMessagePacket::packet_from_bytes(&mut byte_reader).is_err());
+    }
+}
@@ -2,19 +2,30 @@
 #![cfg_attr(feature = "interface", feature(impl_trait_in_assoc_type))]
 
 pub mod handler;
+#[cfg(feature = "decode-hook")]
+mod hook;
+mod ping;
 mod position;
+pub mod registry;
+pub mod replay;
+mod version;
 
 use std::net::Ipv4Addr;
 
 use ragnarok_bytes::{
-    ByteConvertable, ByteReader, ByteWriter, ConversionError, ConversionResult, ConversionResultExt, FixedByteSize, FromBytes, ToBytes,
+    ByteConvertable, ByteReader, ByteWriter, ConversionError, ConversionErrorType, ConversionResult, ConversionResultExt, FixedByteSize,
+    FixedString, FromBytes, Reserved, ToBytes,
 };
 #[cfg(feature = "derive")]
 pub use ragnarok_macros::{CharacterServer, ClientPacket, LoginServer, MapServer, Packet, ServerPacket};
 #[cfg(not(feature = "derive"))]
 use ragnarok_macros::{CharacterServer, ClientPacket, LoginServer, MapServer, Packet, ServerPacket};
 
+#[cfg(feature = "decode-hook")]
+pub use self::hook::{clear_decode_error_hook, set_decode_error_hook};
+pub use self::ping::PingTracker;
 pub use self::position::{Direction, WorldPosition, WorldPosition2};
+pub use self::version::PacketVersion;
 
 // To make proc macros work in korangar_interface.
 extern crate self as ragnarok_packets;
@@ -69,13 +80,32 @@ where
     T: Packet,
 {
     fn packet_from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        #[cfg(feature = "decode-hook")]
+        let save_point = byte_reader.create_save_point();
+
+        let offset = byte_reader.get_offset();
         let header = PacketHeader::from_bytes(byte_reader)?;
 
-        if header != Self::HEADER {
-            return Err(ConversionError::from_message("mismatched header"));
+        let result = match header == Self::HEADER {
+            true => Self::payload_from_bytes(byte_reader),
+            false => Err(ConversionError::from_error_type(ConversionErrorType::MismatchedHeader {
+                expected: Self::HEADER.0,
+                actual: header.0,
+                offset,
+            })),
+        };
+
+        #[cfg(feature = "decode-hook")]
+        if let Err(error) = &result {
+            // Report everything from the start of the packet, but leave the reader
+            // where the error occurred.
+            let error_point = byte_reader.create_save_point();
+            byte_reader.restore_save_point(save_point);
+            hook::report_decode_error(header, byte_reader.subslice_from_here(), error);
+            byte_reader.restore_save_point(error_point);
         }
 
-        Self::payload_from_bytes(byte_reader)
+        result
     }
 
     fn packet_to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
@@ -100,6 +130,76 @@ pub trait CharacterServerPacket: Packet {}
 /// Marker trait for map server packets.
 pub trait MapServerPacket: Packet {}
 
+/// Boolean that is sent as two bytes. Any non-zero value is `true`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct Bool16(pub bool);
+
+impl FixedByteSize for Bool16 {
+    fn size_in_bytes() -> usize {
+        u16::size_in_bytes()
+    }
+}
+
+impl FromBytes for Bool16 {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        u16::from_bytes(byte_reader).trace::<Self>().map(|value| Self(value != 0))
+    }
+}
+
+impl ToBytes for Bool16 {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        (self.0 as u16).to_bytes(byte_writer)
+    }
+}
+
+impl From<bool> for Bool16 {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bool16> for bool {
+    fn from(value: Bool16) -> Self {
+        value.0
+    }
+}
+
+/// Boolean that is sent as four bytes. Any non-zero value is `true`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct Bool32(pub bool);
+
+impl FixedByteSize for Bool32 {
+    fn size_in_bytes() -> usize {
+        u32::size_in_bytes()
+    }
+}
+
+impl FromBytes for Bool32 {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        u32::from_bytes(byte_reader).trace::<Self>().map(|value| Self(value != 0))
+    }
+}
+
+impl ToBytes for Bool32 {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        (self.0 as u32).to_bytes(byte_writer)
+    }
+}
+
+impl From<bool> for Bool32 {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bool32> for bool {
+    fn from(value: Bool32) -> Self {
+        value.0
+    }
+}
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ClientTick(pub u32);
@@ -148,6 +248,14 @@ pub struct Price(pub u32);
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct AttackRange(pub u16);
 
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct HairColor(pub u16);
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct HairStyle(pub u16);
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ServerAddress(pub [u8; 4]);
@@ -180,7 +288,45 @@ pub struct LargeTilePosition {
     pub y: u32,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+impl TilePosition {
+    /// Number of steps needed to reach `other` when diagonal steps are
+    /// allowed. This is the distance the server uses for range checks.
+    pub fn chebyshev_distance(&self, other: TilePosition) -> u16 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Number of steps needed to reach `other` when only horizontal and
+    /// vertical steps are allowed.
+    pub fn manhattan_distance(&self, other: TilePosition) -> u32 {
+        self.x.abs_diff(other.x) as u32 + self.y.abs_diff(other.y) as u32
+    }
+
+    /// Whether `other` is one of the eight surrounding tiles.
+    pub fn is_adjacent(&self, other: TilePosition) -> bool {
+        self.chebyshev_distance(other) == 1
+    }
+}
+
+impl LargeTilePosition {
+    /// Number of steps needed to reach `other` when diagonal steps are
+    /// allowed. This is the distance the server uses for range checks.
+    pub fn chebyshev_distance(&self, other: LargeTilePosition) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Number of steps needed to reach `other` when only horizontal and
+    /// vertical steps are allowed.
+    pub fn manhattan_distance(&self, other: LargeTilePosition) -> u64 {
+        self.x.abs_diff(other.x) as u64 + self.y.abs_diff(other.y) as u64
+    }
+
+    /// Whether `other` is one of the eight surrounding tiles.
+    pub fn is_adjacent(&self, other: LargeTilePosition) -> bool {
+        self.chebyshev_distance(other) == 1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ColorBGRA {
     pub blue: u8,
@@ -189,7 +335,7 @@ pub struct ColorBGRA {
     pub alpha: u8,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ColorRGBA {
     pub red: u8,
@@ -198,6 +344,34 @@ pub struct ColorRGBA {
     pub alpha: u8,
 }
 
+impl ColorBGRA {
+    /// Normalized channels in RGBA order.
+    pub fn to_rgba_f32(&self) -> [f32; 4] {
+        [self.red, self.green, self.blue, self.alpha].map(|channel| channel as f32 / 255.0)
+    }
+}
+
+impl ColorRGBA {
+    /// Normalized channels in RGBA order.
+    pub fn to_rgba_f32(&self) -> [f32; 4] {
+        [self.red, self.green, self.blue, self.alpha].map(|channel| channel as f32 / 255.0)
+    }
+}
+
+impl From<ColorBGRA> for ColorRGBA {
+    fn from(color: ColorBGRA) -> Self {
+        let ColorBGRA { blue, green, red, alpha } = color;
+        Self { red, green, blue, alpha }
+    }
+}
+
+impl From<ColorRGBA> for ColorBGRA {
+    fn from(color: ColorRGBA) -> Self {
+        let ColorRGBA { red, green, blue, alpha } = color;
+        Self { blue, green, red, alpha }
+    }
+}
+
 /// Item index is always actual index + 2.
 #[derive(Clone, Copy, Debug, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
@@ -205,7 +379,12 @@ pub struct InventoryIndex(pub u16);
 
 impl FromBytes for InventoryIndex {
     fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        u16::from_bytes(byte_reader).map(|raw| Self(raw - 2))
+        let raw = u16::from_bytes(byte_reader).trace::<Self>()?;
+
+        match raw.checked_sub(2) {
+            Some(index) => Ok(Self(index)),
+            None => Err(ConversionError::from_message(format!("invalid inventory index {raw}"))).trace::<Self>(),
+        }
     }
 }
 
@@ -215,6 +394,28 @@ impl ToBytes for InventoryIndex {
     }
 }
 
+/// Storage index is always actual index + 1.
+#[derive(Clone, Copy, Debug, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct StorageIndex(pub u16);
+
+impl FromBytes for StorageIndex {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        let raw = u16::from_bytes(byte_reader).trace::<Self>()?;
+
+        match raw.checked_sub(1) {
+            Some(index) => Ok(Self(index)),
+            None => Err(ConversionError::from_message(format!("invalid storage index {raw}"))).trace::<Self>(),
+        }
+    }
+}
+
+impl ToBytes for StorageIndex {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        u16::to_bytes(&(self.0 + 1), byte_writer)
+    }
+}
+
 #[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ItemId(pub u32);
@@ -228,6 +429,40 @@ pub enum Sex {
     Server,
 }
 
+/// The sex of a player character. Unlike [`Sex`], this can only be male or
+/// female, since the server rejects anything else when logging in or creating
+/// a character.
+#[derive(Copy, Debug, Clone, ByteConvertable, FixedByteSize, PartialEq, Eq)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum CharacterSex {
+    Female,
+    Male,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct InvalidCharacterSexError;
+
+impl TryFrom<Sex> for CharacterSex {
+    type Error = InvalidCharacterSexError;
+
+    fn try_from(value: Sex) -> Result<Self, Self::Error> {
+        match value {
+            Sex::Female => Ok(CharacterSex::Female),
+            Sex::Male => Ok(CharacterSex::Male),
+            Sex::Both | Sex::Server => Err(InvalidCharacterSexError),
+        }
+    }
+}
+
+impl From<CharacterSex> for Sex {
+    fn from(value: CharacterSex) -> Self {
+        match value {
+            CharacterSex::Female => Sex::Female,
+            CharacterSex::Male => Sex::Male,
+        }
+    }
+}
+
 /// Sent by the client to the login server.
 /// The very first packet sent when logging in, it is sent after the user has
 /// entered email and password.
@@ -354,9 +589,10 @@ pub struct Packet0b18 {
 pub struct MapServerLoginSuccessPacket {
     pub client_tick: ClientTick,
     pub position: WorldPosition,
-    /// Always [5, 5] on rAthena
-    #[new_default]
-    pub ignored: [u8; 2],
+    /// Always 5 on rAthena.
+    pub x_size: u8,
+    /// Always 5 on rAthena.
+    pub y_size: u8,
     pub font: u16,
 }
 
@@ -431,13 +667,19 @@ pub struct CharacterSelectionFailedPacket {
 #[header(0x0AC5)]
 pub struct CharacterSelectionSuccessPacket {
     pub character_id: CharacterId,
-    #[length(16)]
-    pub map_name: String,
+    pub map_name: FixedString<16>,
     pub map_server_ip: ServerAddress,
     pub map_server_port: u16,
-    // NOTE: Could be `new_default` but Rust doesn't implement `[u8; 128]: Default`.
-    #[new_value([0; 128])]
-    pub unknown: [u8; 128],
+    #[new_default]
+    #[cfg_attr(feature = "interface", hidden_element)]
+    pub unknown: Reserved<128>,
+}
+
+impl CharacterSelectionSuccessPacket {
+    /// Get the [normalized](normalize_map_name) name of the map.
+    pub fn normalized_map_name(&self) -> &str {
+        normalize_map_name(&self.map_name)
+    }
 }
 
 #[derive(Debug, Clone, ByteConvertable)]
@@ -480,9 +722,10 @@ pub struct CharacterServerInformation {
     pub server_name: String,
     pub user_count: u16,
     pub server_type: u16, // ServerType
-    pub display_new: u16, // bool16 ?
-    #[new_value([0; 128])]
-    pub unknown: [u8; 128],
+    pub display_new: Bool16,
+    #[new_default]
+    #[cfg_attr(feature = "interface", hidden_element)]
+    pub unknown: Reserved<128>,
 }
 
 /// Sent by the client to the character server after after successfully logging
@@ -497,7 +740,7 @@ pub struct CharacterServerLoginPacket {
     pub login_id2: u32,
     #[new_default]
     pub unknown: u16,
-    pub sex: Sex,
+    pub sex: CharacterSex,
 }
 
 /// Sent by the client to the map server after after successfully selecting a
@@ -523,6 +766,154 @@ pub struct Packet8302 {
     pub entity_id: EntityId,
 }
 
+/// Mirrors `e_job` of rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u16)]
+#[repr(u16)]
+pub enum Job {
+    Novice,
+    Swordman,
+    Magician,
+    Archer,
+    Acolyte,
+    Merchant,
+    Thief,
+    Knight,
+    Priest,
+    Wizard,
+    Blacksmith,
+    Hunter,
+    Assassin,
+    KnightMounted,
+    Crusader,
+    Monk,
+    Sage,
+    Rogue,
+    Alchemist,
+    Bard,
+    Dancer,
+    CrusaderMounted,
+    Wedding,
+    SuperNovice,
+    Gunslinger,
+    Ninja,
+    Christmas,
+    Summer,
+    Hanbok,
+    Oktoberfest,
+    Summer2,
+    HighNovice = 4001,
+    HighSwordman,
+    HighMagician,
+    HighArcher,
+    HighAcolyte,
+    HighMerchant,
+    HighThief,
+    LordKnight,
+    HighPriest,
+    HighWizard,
+    Whitesmith,
+    Sniper,
+    AssassinCross,
+    LordKnightMounted,
+    Paladin,
+    Champion,
+    Professor,
+    Stalker,
+    Creator,
+    Clown,
+    Gypsy,
+    PaladinMounted,
+    BabyNovice,
+    BabySwordman,
+    BabyMagician,
+    BabyArcher,
+    BabyAcolyte,
+    BabyMerchant,
+    BabyThief,
+    BabyKnight,
+    BabyPriest,
+    BabyWizard,
+    BabyBlacksmith,
+    BabyHunter,
+    BabyAssassin,
+    BabyKnightMounted,
+    BabyCrusader,
+    BabyMonk,
+    BabySage,
+    BabyRogue,
+    BabyAlchemist,
+    BabyBard,
+    BabyDancer,
+    BabyCrusaderMounted,
+    SuperBaby,
+    Taekwon,
+    StarGladiator,
+    StarGladiatorUnion,
+    SoulLinker,
+    Gangsi,
+    DeathKnight,
+    DarkCollector,
+    RuneKnight = 4054,
+    Warlock,
+    Ranger,
+    ArchBishop,
+    Mechanic,
+    GuillotineCross,
+    RuneKnightTrans,
+    WarlockTrans,
+    RangerTrans,
+    ArchBishopTrans,
+    MechanicTrans,
+    GuillotineCrossTrans,
+    RoyalGuard,
+    Sorcerer,
+    Minstrel,
+    Wanderer,
+    Sura,
+    Genetic,
+    ShadowChaser,
+    RoyalGuardTrans,
+    SorcererTrans,
+    MinstrelTrans,
+    WandererTrans,
+    SuraTrans,
+    GeneticTrans,
+    ShadowChaserTrans,
+    RuneKnightDragon,
+    RuneKnightTransDragon,
+    RoyalGuardGryphon,
+    RoyalGuardTransGryphon,
+    RangerWarg,
+    RangerTransWarg,
+    MechanicMadoGear,
+    MechanicTransMadoGear,
+    BabyRuneKnight = 4096,
+    BabyWarlock,
+    BabyRanger,
+    BabyArchBishop,
+    BabyMechanic,
+    BabyGuillotineCross,
+    BabyRoyalGuard,
+    BabySorcerer,
+    BabyMinstrel,
+    BabyWanderer,
+    BabySura,
+    BabyGenetic,
+    BabyShadowChaser,
+    BabyRuneKnightDragon,
+    BabyRoyalGuardGryphon,
+    BabyRangerWarg,
+    BabyMechanicMadoGear,
+    ExpandedSuperNovice = 4190,
+    ExpandedSuperBaby,
+    Kagerou = 4211,
+    Oboro,
+    Rebellion = 4215,
+    Summoner = 4218,
+}
+
 /// Sent by the client to the character server when the player tries to create
 /// a new character.
 /// Attempts to create a new character in an empty slot using the provided
@@ -534,15 +925,18 @@ pub struct CreateCharacterPacket {
     #[length(24)]
     pub name: String,
     pub slot: u8,
-    pub hair_color: u16, // TODO: HairColor
-    pub hair_style: u16, // TODO: HairStyle
-    pub start_job: u16,  // TODO: Job
+    pub hair_color: HairColor,
+    pub hair_style: HairStyle,
+    pub start_job: Job,
     #[new_default]
     pub unknown: [u8; 2],
-    pub sex: Sex,
+    pub sex: CharacterSex,
 }
 
-#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+/// Layout of a character in the character list. Fields that were added in
+/// later protocol versions are only present if the packet version of the
+/// reader includes them.
+#[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct CharacterInformation {
     pub character_id: CharacterId,
@@ -587,12 +981,22 @@ pub struct CharacterInformation {
     #[length(16)]
     pub map_name: String,
     pub deletion_reverse_date: i32,
-    pub robe_palette: i32,
-    pub character_slot_change_count: i32,
-    pub character_name_change_count: i32,
+    #[packet_version_equals_or_above(20110111)]
+    pub robe_palette: Option<i32>,
+    #[packet_version_equals_or_above(20110928)]
+    pub character_slot_change_count: Option<i32>,
+    #[packet_version_equals_or_above(20111025)]
+    pub character_name_change_count: Option<i32>,
     pub sex: Sex,
 }
 
+impl CharacterInformation {
+    /// Get the [normalized](normalize_map_name) name of the map.
+    pub fn normalized_map_name(&self) -> &str {
+        normalize_map_name(&self.map_name)
+    }
+}
+
 #[cfg(feature = "interface")]
 impl rust_state::VecItem for CharacterInformation {
     // TODO: Use CharacterId
@@ -659,6 +1063,50 @@ pub struct RequestWarpToMapPacket {
     pub position: TilePosition,
 }
 
+/// Error when creating a [`RequestWarpToMapPacket`] with a map name that can't
+/// be sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidMapNameError {
+    /// The encoded name plus the null terminator exceeds 16 bytes.
+    TooLong { length: usize },
+    /// The name contains a null byte, so the server would only read the part
+    /// before it.
+    ContainsNull,
+}
+
+impl RequestWarpToMapPacket {
+    const MAXIMUM_NAME_LENGTH: usize = 16;
+
+    /// Like [`RequestWarpToMapPacket::new`], but checks that the map name fits
+    /// into the packet, including its null terminator.
+    pub fn try_new(map_name: String, position: TilePosition) -> Result<Self, InvalidMapNameError> {
+        if map_name.contains('\0') {
+            return Err(InvalidMapNameError::ContainsNull);
+        }
+
+        let (encoded, ..) = ragnarok_bytes::encoding::EUC_KR.encode(&map_name);
+        let length = encoded.len();
+
+        if length + 1 > Self::MAXIMUM_NAME_LENGTH {
+            return Err(InvalidMapNameError::TooLong { length });
+        }
+
+        Ok(Self::new(map_name, position))
+    }
+}
+
+/// Get the name of a map without null padding and without the `.gat` or `.rsw`
+/// extension. The server is inconsistent about including the extension, so
+/// map names should always be compared in this form.
+pub fn normalize_map_name(map_name: &str) -> &str {
+    let map_name = map_name.split('\0').next().unwrap_or_default();
+
+    map_name
+        .strip_suffix(".gat")
+        .or_else(|| map_name.strip_suffix(".rsw"))
+        .unwrap_or(map_name)
+}
+
 /// Sent by the map server to the client.
 /// Informs the client that an entity is pathing towards a new position.
 /// Provides the initial position and destination of the movement, as well as a
@@ -693,6 +1141,36 @@ pub struct PlayerMovePacket {
     pub from_to: WorldPosition2,
 }
 
+/// Direction the head of a character is turned to, relative to the direction
+/// of its body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u16)]
+pub enum HeadDirection {
+    Straight,
+    Right,
+    Left,
+}
+
+/// Sent by the client to the map server when the player turns in place.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0361)]
+pub struct ChangeDirectionPacket {
+    pub head_direction: HeadDirection,
+    pub body_direction: Direction,
+}
+
+/// Sent by the map server to the client when an entity turns in place.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x009C)]
+pub struct EntityDirectionChangedPacket {
+    pub entity_id: EntityId,
+    pub head_direction: HeadDirection,
+    pub body_direction: Direction,
+}
+
 /// Sent by the client to the character server when the user tries to delete a
 /// character.
 /// Attempts to delete a character from the user account using the provided
@@ -811,6 +1289,226 @@ pub struct NewMailStatusPacket {
     pub new_available: u8,
 }
 
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MailId(pub u64);
+
+/// Mirrors `RODEX_OPENTYPE` of rAthena. Newer clients only have a single
+/// mailbox and always use [`MailboxType::Unset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum MailboxType {
+    Mail,
+    Account,
+    Return,
+    Unset,
+}
+
+bitflags::bitflags! {
+    /// Contents of a mail. Mirrors `MAIL_TYPE` of rAthena.
+    #[derive(Debug, Clone)]
+    #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+    pub struct MailFlags: u8 {
+        const TEXT = 0b000001;
+        const ZENY = 0b000010;
+        const ITEM = 0b000100;
+        const NPC = 0b001000;
+        const RETURNED = 0b010000;
+        const ACCOUNT = 0b100000;
+    }
+}
+
+impl FixedByteSize for MailFlags {
+    fn size_in_bytes() -> usize {
+        <<Self as bitflags::Flags>::Bits as FixedByteSize>::size_in_bytes()
+    }
+}
+
+impl FromBytes for MailFlags {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(Self::from_bits_retain)
+    }
+}
+
+impl ToBytes for MailFlags {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        self.bits().to_bytes(byte_writer)
+    }
+}
+
+/// Sent by the client to the map server when opening the mailbox. The
+/// server responds with a [`MailListPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0AC0)]
+pub struct OpenMailboxPacket {
+    /// Only mails older than this one are sent. Use `MailId(0)` to get the
+    /// newest mails.
+    pub upper_mail_id: MailId,
+    #[new_default]
+    #[cfg_attr(feature = "interface", hidden_element)]
+    pub unknown: Reserved<16>,
+}
+
+#[derive(Debug, Clone, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MailHeader {
+    pub mail_id: MailId,
+    pub is_read: u8,
+    /// Tells whether the mail has zeny or items attached.
+    pub flags: MailFlags,
+    #[length(24)]
+    pub sender_name: String,
+    /// Seconds until the mail expires.
+    pub expiration_time: i32,
+    pub title_length: u16,
+    #[length(title_length)]
+    pub title: String,
+}
+
+/// Sent by the map server to the client in response to an
+/// [`OpenMailboxPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0AC2)]
+#[variable_length]
+pub struct MailListPacket {
+    /// Set if there are no more mails to request.
+    pub is_end: u8,
+    #[length_remaining]
+    pub mails: Vec<MailHeader>,
+}
+
+/// Sent by the client to the map server to read the content of a mail. The
+/// server responds with a [`MailDetailPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09EA)]
+pub struct ReadMailPacket {
+    pub mailbox_type: MailboxType,
+    pub mail_id: MailId,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct MailItemInformation {
+    pub amount: u16,
+    pub item_id: ItemId,
+    pub is_identified: u8,
+    pub is_damaged: u8,
+    pub refinement_level: u8,
+    pub slot: [u32; 4],
+    pub equip_position: EquipPosition,
+    pub item_type: u8,
+    pub w_item_sprite_number: u16,
+    pub bind_on_equip_type: u16,
+    pub option_data: [ItemOptions; 5],
+    pub enchantment_level: u8,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`ReadMailPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09EB)]
+#[variable_length]
+pub struct MailDetailPacket {
+    pub mailbox_type: MailboxType,
+    pub mail_id: MailId,
+    pub text_length: u16,
+    pub zeny: u64,
+    #[new_derive]
+    pub item_count: u8,
+    #[length(text_length)]
+    pub text: String,
+    #[repeating(item_count)]
+    pub items: Vec<MailItemInformation>,
+}
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct AuctionId(pub u32);
+
+/// Filter of an [`AuctionSearchPacket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u16)]
+pub enum AuctionSearchType {
+    Armor,
+    Weapon,
+    Card,
+    Miscellaneous,
+    /// Items with a name containing the search text.
+    Name,
+    /// Items with a current bid of at most the given price.
+    Price,
+    /// Auctions of the player.
+    Selling,
+    /// Auctions the player placed a bid on.
+    Bidding,
+}
+
+/// Sent by the client to the map server to search the auction house. The
+/// server responds with an [`AuctionItemsListPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0251)]
+pub struct AuctionSearchPacket {
+    pub search_type: AuctionSearchType,
+    /// Only used by [`AuctionSearchType::Price`].
+    pub price: Price,
+    /// Only used by [`AuctionSearchType::Name`].
+    #[length(24)]
+    pub search_text: String,
+    pub page: u16,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct AuctionEntry {
+    pub auction_id: AuctionId,
+    #[length(24)]
+    pub seller_name: String,
+    /// Auctions still use the old 2 byte item ids.
+    pub item_id: u16,
+    pub item_type: u32,
+    pub amount: u16,
+    pub is_identified: u8,
+    pub is_damaged: u8,
+    pub refinement_level: u8,
+    pub cards: [u16; 4],
+    pub current_bid: Price,
+    pub buyout_price: Price,
+    /// Empty if nobody placed a bid yet.
+    #[length(24)]
+    pub buyer_name: String,
+    /// Unix timestamp at which the auction ends.
+    pub end_time: u32,
+}
+
+/// Sent by the map server to the client in response to an
+/// [`AuctionSearchPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0252)]
+#[variable_length]
+pub struct AuctionItemsListPacket {
+    pub page_count: u32,
+    #[new_derive]
+    pub auction_count: u32,
+    #[repeating(auction_count)]
+    pub auctions: Vec<AuctionEntry>,
+}
+
+/// Sent by the client to the map server to bid on an auction.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x024F)]
+pub struct AuctionBidPacket {
+    pub auction_id: AuctionId,
+    pub bid: Price,
+}
+
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct AchievementData {
@@ -847,7 +1545,26 @@ pub struct AchievementListPacket {
     pub acheivement_data: Vec<AchievementData>,
 }
 
-#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+/// Sent by the client to the map server to claim the reward of a completed
+/// achievement.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A25)]
+pub struct RequestAchievementRewardPacket {
+    pub achievement_id: u32,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`RequestAchievementRewardPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A26)]
+pub struct AchievementRewardResultPacket {
+    pub received: u8,
+    pub achievement_id: u32,
+}
+
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0ADE)]
 pub struct CriticalWeightUpdatePacket {
@@ -864,7 +1581,8 @@ pub struct SpriteChangePacket {
     pub value2: u32,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+/// Mirrors `look_type` of rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub enum SpriteChangeType {
     Base,
@@ -873,7 +1591,7 @@ pub enum SpriteChangeType {
     HeadBottom,
     HeadTop,
     HeadMiddle,
-    HairCollor,
+    HairColor,
     ClothesColor,
     Shield,
     Shoes,
@@ -883,12 +1601,23 @@ pub enum SpriteChangeType {
     Body2,
 }
 
+/// Mirrors `e_inventory_type` of rAthena. The contents of all of these are
+/// sent with the same packets, so this is used to tell them apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum InventoryType {
+    Inventory,
+    Cart,
+    Storage,
+    GuildStorage,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0B08)]
 #[variable_length]
 pub struct InventoyStartPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[length_remaining]
     pub inventory_name: String,
 }
@@ -897,7 +1626,7 @@ pub struct InventoyStartPacket {
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0B0B)]
 pub struct InventoyEndPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     pub flag: u8, // maybe char ?
 }
 
@@ -910,6 +1639,8 @@ pub struct ItemOptions {
 }
 
 bitflags::bitflags! {
+    /// Flags of a regular item. Note that the bits don't line up with
+    /// [`EquippableItemFlags`], since regular items can't be damaged.
     #[derive(Debug, Clone)]
     #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
     pub struct RegularItemFlags: u8 {
@@ -926,7 +1657,8 @@ impl FixedByteSize for RegularItemFlags {
 
 impl FromBytes for RegularItemFlags {
     fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(|raw| Self::from_bits(raw).expect("Invalid equip position"))
+        // The remaining bits are unused, so we keep them instead of failing.
+        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(Self::from_bits_retain)
     }
 }
 
@@ -936,10 +1668,15 @@ impl ToBytes for RegularItemFlags {
     }
 }
 
+/// Regular item in the inventory or the cart. Items in the storage use the
+/// same layout with a [`StorageIndex`].
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-pub struct RegularItemInformation {
-    pub index: InventoryIndex,
+pub struct RegularItemInformation<Index = InventoryIndex>
+where
+    Index: FromBytes + ToBytes + FixedByteSize + 'static,
+{
+    pub index: Index,
     pub item_id: ItemId,
     pub item_type: u8,
     pub amount: u16,
@@ -954,18 +1691,19 @@ pub struct RegularItemInformation {
 #[header(0x0B09)]
 #[variable_length]
 pub struct RegularItemListPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[repeating_remaining]
     pub item_information: Vec<RegularItemInformation>,
 }
 
 bitflags::bitflags! {
+    /// Flags of an equippable item.
     #[derive(Debug, Clone)]
     #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
     pub struct EquippableItemFlags: u8 {
         const IDENTIFIED = 0b001;
         const IS_BROKEN = 0b010;
-        const IN_ETC_TAB = 0b110;
+        const IN_ETC_TAB = 0b100;
     }
 }
 
@@ -977,7 +1715,8 @@ impl FixedByteSize for EquippableItemFlags {
 
 impl FromBytes for EquippableItemFlags {
     fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(|raw| Self::from_bits(raw).expect("Invalid equip position"))
+        // The remaining bits are unused, so we keep them instead of failing.
+        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(Self::from_bits_retain)
     }
 }
 
@@ -987,10 +1726,15 @@ impl ToBytes for EquippableItemFlags {
     }
 }
 
+/// Equippable item in the inventory or the cart. Items in the storage use the
+/// same layout with a [`StorageIndex`].
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-pub struct EquippableItemInformation {
-    pub index: InventoryIndex,
+pub struct EquippableItemInformation<Index = InventoryIndex>
+where
+    Index: FromBytes + ToBytes + FixedByteSize + 'static,
+{
+    pub index: Index,
     pub item_id: ItemId,
     pub item_type: u8,
     pub equip_position: EquipPosition,
@@ -1011,7 +1755,7 @@ pub struct EquippableItemInformation {
 #[header(0x0B39)]
 #[variable_length]
 pub struct EquippableItemListPacket {
-    pub inventory_type: u8,
+    pub inventory_type: InventoryType,
     #[repeating_remaining]
     pub item_information: Vec<EquippableItemInformation>,
 }
@@ -1176,6 +1920,98 @@ pub enum StatType {
     CriticalDamageRate(u32),
 }
 
+/// Defines the numeric code of every stat on the wire. Both directions of the
+/// mapping are generated from the same table, so they can't drift apart.
+macro_rules! stat_codes {
+    ($($variant:ident($($value:literal),*) => $code:literal,)*) => {
+        impl StatType {
+            /// The numeric code of the stat on the wire.
+            pub fn status_code(&self) -> u16 {
+                match self {
+                    $(Self::$variant(..) => $code,)*
+                }
+            }
+
+            /// Create the stat for the given numeric code with all values set to
+            /// zero. Returns `None` if the code is unknown.
+            pub fn from_code(code: u16) -> Option<Self> {
+                match code {
+                    $($code => Some(Self::$variant($($value),*)),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+stat_codes! {
+    MovementSpeed(0) => 0,
+    BaseExperience(0) => 1,
+    JobExperience(0) => 2,
+    Karma(0) => 3,
+    Manner(0) => 4,
+    HealthPoints(0) => 5,
+    MaximumHealthPoints(0) => 6,
+    SpellPoints(0) => 7,
+    MaximumSpellPoints(0) => 8,
+    StatPoints(0) => 9,
+    BaseLevel(0) => 11,
+    SkillPoint(0) => 12,
+    Strength(0, 0) => 13,
+    Agility(0, 0) => 14,
+    Vitality(0, 0) => 15,
+    Intelligence(0, 0) => 16,
+    Dexterity(0, 0) => 17,
+    Luck(0, 0) => 18,
+    Zeny(0) => 20,
+    NextBaseExperience(0) => 22,
+    NextJobExperience(0) => 23,
+    Weight(0) => 24,
+    MaximumWeight(0) => 25,
+    StrengthStatPointCost(0) => 32,
+    AgilityStatPointCost(0) => 33,
+    VitalityStatPointCost(0) => 34,
+    IntelligenceStatPointCost(0) => 35,
+    DexterityStatPointCost(0) => 36,
+    LuckStatPointCost(0) => 37,
+    Attack1(0) => 41,
+    Attack2(0) => 42,
+    MagicAttack1(0) => 43,
+    MagicAttack2(0) => 44,
+    Defense1(0) => 45,
+    Defense2(0) => 46,
+    MagicDefense1(0) => 47,
+    MagicDefense2(0) => 48,
+    Hit(0) => 49,
+    Flee1(0) => 50,
+    Flee2(0) => 51,
+    Critical(0) => 52,
+    AttackSpeed(0) => 53,
+    JobLevel(0) => 55,
+    CartInfo(0, 0, 0) => 99,
+    Power(0, 0) => 219,
+    Stamina(0, 0) => 220,
+    Wisdom(0, 0) => 221,
+    Spell(0, 0) => 222,
+    Concentration(0, 0) => 223,
+    Creativity(0, 0) => 224,
+    PhysicalAttack(0) => 225,
+    SpellMagicAttack(0) => 226,
+    Resistance(0) => 227,
+    MagicResistance(0) => 228,
+    HealingPlus(0) => 229,
+    CriticalDamageRate(0) => 230,
+    TraitPoint(0) => 231,
+    ActivityPoints(0) => 232,
+    MaximumActivityPoints(0) => 233,
+    PowerStatPointCost(0) => 247,
+    StaminaStatPointCost(0) => 248,
+    WisdomStatPointCost(0) => 249,
+    SpellStatPointCost(0) => 250,
+    ConcentrationStatPointCost(0) => 251,
+    CreativitySpellPointCost(0) => 252,
+}
+
 impl FromBytes for StatType {
     fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
         /// For some reason, the stats are packed into the upper two bytes of an
@@ -1190,83 +2026,198 @@ impl FromBytes for StatType {
             Ok((base as i32, bonus as i32))
         }
 
-        let stat = match u16::from_bytes(byte_reader).trace::<Self>()? {
-            0 => u32::from_bytes(byte_reader).map(Self::MovementSpeed),
-            1 => u64::from_bytes(byte_reader).map(Self::BaseExperience),
-            2 => u64::from_bytes(byte_reader).map(Self::JobExperience),
-            3 => u32::from_bytes(byte_reader).map(Self::Karma),
-            4 => u32::from_bytes(byte_reader).map(Self::Manner),
-            5 => u32::from_bytes(byte_reader).map(Self::HealthPoints),
-            6 => u32::from_bytes(byte_reader).map(Self::MaximumHealthPoints),
-            7 => u32::from_bytes(byte_reader).map(Self::SpellPoints),
-            8 => u32::from_bytes(byte_reader).map(Self::MaximumSpellPoints),
-            9 => u32::from_bytes(byte_reader).map(Self::StatPoints),
-            11 => u32::from_bytes(byte_reader).map(Self::BaseLevel),
-            12 => u32::from_bytes(byte_reader).map(Self::SkillPoint),
-            13 => weirdly_formatted_stat(byte_reader).map(|(base, bonus)| Self::Strength(base, bonus)),
-            14 => weirdly_formatted_stat(byte_reader).map(|(base, bonus)| Self::Agility(base, bonus)),
-            15 => weirdly_formatted_stat(byte_reader).map(|(base, bonus)| Self::Vitality(base, bonus)),
-            16 => weirdly_formatted_stat(byte_reader).map(|(base, bonus)| Self::Intelligence(base, bonus)),
-            17 => weirdly_formatted_stat(byte_reader).map(|(base, bonus)| Self::Dexterity(base, bonus)),
-            18 => weirdly_formatted_stat(byte_reader).map(|(base, bonus)| Self::Luck(base, bonus)),
-            20 => u32::from_bytes(byte_reader).map(Self::Zeny),
-            22 => u64::from_bytes(byte_reader).map(Self::NextBaseExperience),
-            23 => u64::from_bytes(byte_reader).map(Self::NextJobExperience),
-            24 => u32::from_bytes(byte_reader).map(Self::Weight),
-            25 => u32::from_bytes(byte_reader).map(Self::MaximumWeight),
-            32 => u8::from_bytes(byte_reader).map(Self::StrengthStatPointCost),
-            33 => u8::from_bytes(byte_reader).map(Self::AgilityStatPointCost),
-            34 => u8::from_bytes(byte_reader).map(Self::VitalityStatPointCost),
-            35 => u8::from_bytes(byte_reader).map(Self::IntelligenceStatPointCost),
-            36 => u8::from_bytes(byte_reader).map(Self::DexterityStatPointCost),
-            37 => u8::from_bytes(byte_reader).map(Self::LuckStatPointCost),
-            41 => u32::from_bytes(byte_reader).map(Self::Attack1),
-            42 => u32::from_bytes(byte_reader).map(Self::Attack2),
-            43 => u32::from_bytes(byte_reader).map(Self::MagicAttack1),
-            44 => u32::from_bytes(byte_reader).map(Self::MagicAttack2),
-            45 => u32::from_bytes(byte_reader).map(Self::Defense1),
-            46 => u32::from_bytes(byte_reader).map(Self::Defense2),
-            47 => u32::from_bytes(byte_reader).map(Self::MagicDefense1),
-            48 => u32::from_bytes(byte_reader).map(Self::MagicDefense2),
-            49 => u32::from_bytes(byte_reader).map(Self::Hit),
-            50 => u32::from_bytes(byte_reader).map(Self::Flee1),
-            51 => u32::from_bytes(byte_reader).map(Self::Flee2),
-            52 => u32::from_bytes(byte_reader).map(Self::Critical),
-            53 => u32::from_bytes(byte_reader).map(Self::AttackSpeed),
-            55 => u32::from_bytes(byte_reader).map(Self::JobLevel),
-            99 => u16::from_bytes(byte_reader)
-                .and_then(|a| Ok(Self::CartInfo(a, u32::from_bytes(byte_reader)?, u32::from_bytes(byte_reader)?))),
-            219 => u32::from_bytes(byte_reader).and_then(|a| Ok(Self::Power(a, u32::from_bytes(byte_reader)?))),
-            220 => u32::from_bytes(byte_reader).and_then(|a| Ok(Self::Stamina(a, u32::from_bytes(byte_reader)?))),
-            221 => u32::from_bytes(byte_reader).and_then(|a| Ok(Self::Wisdom(a, u32::from_bytes(byte_reader)?))),
-            222 => u32::from_bytes(byte_reader).and_then(|a| Ok(Self::Spell(a, u32::from_bytes(byte_reader)?))),
-            223 => u32::from_bytes(byte_reader).and_then(|a| Ok(Self::Concentration(a, u32::from_bytes(byte_reader)?))),
-            224 => u32::from_bytes(byte_reader).and_then(|a| Ok(Self::Creativity(a, u32::from_bytes(byte_reader)?))),
-            225 => u32::from_bytes(byte_reader).map(Self::PhysicalAttack),
-            226 => u32::from_bytes(byte_reader).map(Self::SpellMagicAttack),
-            227 => u32::from_bytes(byte_reader).map(Self::Resistance),
-            228 => u32::from_bytes(byte_reader).map(Self::MagicResistance),
-            229 => u32::from_bytes(byte_reader).map(Self::HealingPlus),
-            230 => u32::from_bytes(byte_reader).map(Self::CriticalDamageRate),
-            231 => u32::from_bytes(byte_reader).map(Self::TraitPoint),
-            232 => u32::from_bytes(byte_reader).map(Self::ActivityPoints),
-            233 => u32::from_bytes(byte_reader).map(Self::MaximumActivityPoints),
-            247 => u8::from_bytes(byte_reader).map(Self::PowerStatPointCost),
-            248 => u8::from_bytes(byte_reader).map(Self::StaminaStatPointCost),
-            249 => u8::from_bytes(byte_reader).map(Self::WisdomStatPointCost),
-            250 => u8::from_bytes(byte_reader).map(Self::SpellStatPointCost),
-            251 => u8::from_bytes(byte_reader).map(Self::ConcentrationStatPointCost),
-            252 => u8::from_bytes(byte_reader).map(Self::CreativitySpellPointCost),
-            invalid => Err(ConversionError::from_message(format!("invalid stat id {invalid}"))),
-        };
+        let code = u16::from_bytes(byte_reader).trace::<Self>()?;
+        let mut stat = Self::from_code(code)
+            .ok_or_else(|| ConversionError::from_message(format!("invalid stat id {code}")))
+            .trace::<Self>()?;
+
+        match &mut stat {
+            Self::MovementSpeed(value)
+            | Self::Karma(value)
+            | Self::Manner(value)
+            | Self::HealthPoints(value)
+            | Self::MaximumHealthPoints(value)
+            | Self::SpellPoints(value)
+            | Self::MaximumSpellPoints(value)
+            | Self::StatPoints(value)
+            | Self::BaseLevel(value)
+            | Self::SkillPoint(value)
+            | Self::Zeny(value)
+            | Self::Weight(value)
+            | Self::MaximumWeight(value)
+            | Self::Attack1(value)
+            | Self::Attack2(value)
+            | Self::MagicAttack1(value)
+            | Self::MagicAttack2(value)
+            | Self::Defense1(value)
+            | Self::Defense2(value)
+            | Self::MagicDefense1(value)
+            | Self::MagicDefense2(value)
+            | Self::Hit(value)
+            | Self::Flee1(value)
+            | Self::Flee2(value)
+            | Self::Critical(value)
+            | Self::AttackSpeed(value)
+            | Self::JobLevel(value)
+            | Self::PhysicalAttack(value)
+            | Self::SpellMagicAttack(value)
+            | Self::Resistance(value)
+            | Self::MagicResistance(value)
+            | Self::HealingPlus(value)
+            | Self::CriticalDamageRate(value)
+            | Self::TraitPoint(value)
+            | Self::ActivityPoints(value)
+            | Self::MaximumActivityPoints(value) => {
+                *value = u32::from_bytes(byte_reader).trace::<Self>()?;
+            }
+            Self::BaseExperience(value) | Self::JobExperience(value) | Self::NextBaseExperience(value) | Self::NextJobExperience(value) => {
+                *value = u64::from_bytes(byte_reader).trace::<Self>()?;
+            }
+            Self::StrengthStatPointCost(value)
+            | Self::AgilityStatPointCost(value)
+            | Self::VitalityStatPointCost(value)
+            | Self::IntelligenceStatPointCost(value)
+            | Self::DexterityStatPointCost(value)
+            | Self::LuckStatPointCost(value)
+            | Self::PowerStatPointCost(value)
+            | Self::StaminaStatPointCost(value)
+            | Self::WisdomStatPointCost(value)
+            | Self::SpellStatPointCost(value)
+            | Self::ConcentrationStatPointCost(value)
+            | Self::CreativitySpellPointCost(value) => {
+                *value = u8::from_bytes(byte_reader).trace::<Self>()?;
+            }
+            Self::Strength(base, bonus)
+            | Self::Agility(base, bonus)
+            | Self::Vitality(base, bonus)
+            | Self::Intelligence(base, bonus)
+            | Self::Dexterity(base, bonus)
+            | Self::Luck(base, bonus) => {
+                (*base, *bonus) = weirdly_formatted_stat(byte_reader).trace::<Self>()?;
+            }
+            Self::Power(base, bonus)
+            | Self::Stamina(base, bonus)
+            | Self::Wisdom(base, bonus)
+            | Self::Spell(base, bonus)
+            | Self::Concentration(base, bonus)
+            | Self::Creativity(base, bonus) => {
+                *base = u32::from_bytes(byte_reader).trace::<Self>()?;
+                *bonus = u32::from_bytes(byte_reader).trace::<Self>()?;
+            }
+            Self::CartInfo(items, weight, maximum_weight) => {
+                *items = u16::from_bytes(byte_reader).trace::<Self>()?;
+                *weight = u32::from_bytes(byte_reader).trace::<Self>()?;
+                *maximum_weight = u32::from_bytes(byte_reader).trace::<Self>()?;
+            }
+        }
 
-        stat.trace::<Self>()
+        Ok(stat)
     }
 }
 
 impl ToBytes for StatType {
-    fn to_bytes(&self, _byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
-        panic!("this should be derived");
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        /// Inverse of the reading logic, the stats are written into the upper
+        /// two bytes of an `i32`.
+        fn weirdly_formatted_stat(byte_writer: &mut ByteWriter, base: i32, bonus: i32) -> ConversionResult<()> {
+            0i16.to_bytes(byte_writer)?;
+            (base as i16).to_bytes(byte_writer)?;
+            0i16.to_bytes(byte_writer)?;
+            (bonus as i16).to_bytes(byte_writer)?;
+
+            Ok(())
+        }
+
+        byte_writer
+            .write_counted(|writer| {
+                self.status_code().to_bytes(writer)?;
+
+                match self {
+                    Self::MovementSpeed(value)
+                    | Self::Karma(value)
+                    | Self::Manner(value)
+                    | Self::HealthPoints(value)
+                    | Self::MaximumHealthPoints(value)
+                    | Self::SpellPoints(value)
+                    | Self::MaximumSpellPoints(value)
+                    | Self::StatPoints(value)
+                    | Self::BaseLevel(value)
+                    | Self::SkillPoint(value)
+                    | Self::Zeny(value)
+                    | Self::Weight(value)
+                    | Self::MaximumWeight(value)
+                    | Self::Attack1(value)
+                    | Self::Attack2(value)
+                    | Self::MagicAttack1(value)
+                    | Self::MagicAttack2(value)
+                    | Self::Defense1(value)
+                    | Self::Defense2(value)
+                    | Self::MagicDefense1(value)
+                    | Self::MagicDefense2(value)
+                    | Self::Hit(value)
+                    | Self::Flee1(value)
+                    | Self::Flee2(value)
+                    | Self::Critical(value)
+                    | Self::AttackSpeed(value)
+                    | Self::JobLevel(value)
+                    | Self::PhysicalAttack(value)
+                    | Self::SpellMagicAttack(value)
+                    | Self::Resistance(value)
+                    | Self::MagicResistance(value)
+                    | Self::HealingPlus(value)
+                    | Self::CriticalDamageRate(value)
+                    | Self::TraitPoint(value)
+                    | Self::ActivityPoints(value)
+                    | Self::MaximumActivityPoints(value) => {
+                        value.to_bytes(writer)?;
+                    }
+                    Self::BaseExperience(value)
+                    | Self::JobExperience(value)
+                    | Self::NextBaseExperience(value)
+                    | Self::NextJobExperience(value) => {
+                        value.to_bytes(writer)?;
+                    }
+                    Self::StrengthStatPointCost(value)
+                    | Self::AgilityStatPointCost(value)
+                    | Self::VitalityStatPointCost(value)
+                    | Self::IntelligenceStatPointCost(value)
+                    | Self::DexterityStatPointCost(value)
+                    | Self::LuckStatPointCost(value)
+                    | Self::PowerStatPointCost(value)
+                    | Self::StaminaStatPointCost(value)
+                    | Self::WisdomStatPointCost(value)
+                    | Self::SpellStatPointCost(value)
+                    | Self::ConcentrationStatPointCost(value)
+                    | Self::CreativitySpellPointCost(value) => {
+                        value.to_bytes(writer)?;
+                    }
+                    Self::Strength(base, bonus)
+                    | Self::Agility(base, bonus)
+                    | Self::Vitality(base, bonus)
+                    | Self::Intelligence(base, bonus)
+                    | Self::Dexterity(base, bonus)
+                    | Self::Luck(base, bonus) => {
+                        weirdly_formatted_stat(writer, *base, *bonus)?;
+                    }
+                    Self::Power(base, bonus)
+                    | Self::Stamina(base, bonus)
+                    | Self::Wisdom(base, bonus)
+                    | Self::Spell(base, bonus)
+                    | Self::Concentration(base, bonus)
+                    | Self::Creativity(base, bonus) => {
+                        base.to_bytes(writer)?;
+                        bonus.to_bytes(writer)?;
+                    }
+                    Self::CartInfo(items, weight, maximum_weight) => {
+                        items.to_bytes(writer)?;
+                        weight.to_bytes(writer)?;
+                        maximum_weight.to_bytes(writer)?;
+                    }
+                }
+
+                Ok(())
+            })
+            .trace::<Self>()
     }
 }
 
@@ -1350,11 +2301,54 @@ pub struct UpdateStatPacket {
     pub stat_type: StatType,
 }
 
+/// Status effect (icon) of an entity. Mirrors the first entries of
+/// `efst_type` of rAthena, which is what the server sends to the client. These
+/// are not the same as the `sc_type` ids used internally by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u16)]
+pub enum StatusEffect {
+    Provoke,
+    Endure,
+    TwoHandQuicken,
+    Concentration,
+    Hiding,
+    Cloaking,
+    EnchantPoison,
+    PoisonReact,
+    Quagmire,
+    Angelus,
+    Blessing,
+    SignumCrucis,
+    IncreaseAgi,
+    DecreaseAgi,
+    SlowPoison,
+    ImpositioManus,
+    Suffragium,
+    Aspersio,
+    BenedictioSacramenti,
+    KyrieEleison,
+    Magnificat,
+    Gloria,
+    LexAeterna,
+    AdrenalineRush,
+    WeaponPerfection,
+    OverThrust,
+    MaximizePower,
+    Riding,
+    Falcon,
+    PlayDead,
+    Loud,
+    EnergyCoat,
+    #[fallback]
+    Unknown(u16),
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0196)]
 pub struct StatusChangeSequencePacket {
-    pub index: u16,
+    pub index: StatusEffect,
     pub id: u32,
     pub state: u8,
 }
@@ -1438,8 +2432,19 @@ pub enum StatUpType {
 }
 
 impl FromBytes for StatUpType {
-    fn from_bytes<Meta>(_: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        todo!()
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        let stat_id = u16::from_bytes(byte_reader).trace::<Self>()?;
+        let amount = u8::from_bytes(byte_reader).trace::<Self>()?;
+
+        match stat_id {
+            13 => Ok(Self::Strength { amount }),
+            14 => Ok(Self::Agility { amount }),
+            15 => Ok(Self::Vitality { amount }),
+            16 => Ok(Self::Intelligence { amount }),
+            17 => Ok(Self::Dexterity { amount }),
+            18 => Ok(Self::Luck { amount }),
+            invalid => Err(ConversionError::from_message(format!("invalid stat up id {invalid}"))).trace::<Self>(),
+        }
     }
 }
 
@@ -1481,6 +2486,31 @@ pub struct RequestStatUpPacket {
     pub stat_type: StatUpType,
 }
 
+/// Trait stats of 4th jobs. The numeric values are the status codes of the
+/// matching [`StatType`] variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u16)]
+pub enum TraitStatId {
+    #[numeric_value(219)]
+    Power,
+    Stamina,
+    Wisdom,
+    Spell,
+    Concentration,
+    Creativity,
+}
+
+/// Spend trait stat points. The server responds with the updated stats
+/// through [`UpdateStatPacket`] and [`UpdateStatPacket3`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0B24)]
+pub struct IncreaseTraitStatPacket {
+    pub stat_id: TraitStatId,
+    pub amount: u16,
+}
+
 /// rAthena seems to always return [`Success`](RequestStatUpResult::Success),
 /// even if the request fails.
 #[derive(Debug, Clone, ByteConvertable, PartialEq, Eq)]
@@ -1544,6 +2574,246 @@ pub struct GlobalMessagePacket {
     pub message: String,
 }
 
+/// Sent by the client to the map server to whisper to another player. The
+/// server responds with a [`WhisperResultPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0096)]
+#[variable_length]
+pub struct SendWhisperPacket {
+    #[length(24)]
+    pub target_name: String,
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Sent by the map server to the client when another player whispers to the
+/// player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0097)]
+#[variable_length]
+pub struct WhisperPacket {
+    #[length(24)]
+    pub sender_name: String,
+    #[packet_version_equals_or_above(20091104)]
+    pub is_admin: Option<u32>,
+    #[length_remaining]
+    pub message: String,
+}
+
+/// Mirrors the result codes of `clif_wis_end` in rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum WhisperResult {
+    Success,
+    TargetOffline,
+    /// The target is ignoring the player.
+    Ignored,
+    /// The target is ignoring all whispers.
+    IgnoringEveryone,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`SendWhisperPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0098)]
+pub struct WhisperResultPacket {
+    pub result: WhisperResult,
+}
+
+#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct ChatRoomId(pub u32);
+
+/// Sent by the client to the map server to open a chat room.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00D5)]
+#[variable_length]
+pub struct CreateChatRoomPacket {
+    pub member_limit: u16,
+    /// Set if the chat room can be joined without a password.
+    pub is_public: u8,
+    #[length(8)]
+    pub password: String,
+    #[length_remaining]
+    pub title: String,
+}
+
+/// Sent by the map server to the client when a chat room appears or changes.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00D7)]
+#[variable_length]
+pub struct ChatRoomInfoPacket {
+    pub owner_id: AccountId,
+    pub chat_room_id: ChatRoomId,
+    pub member_limit: u16,
+    pub member_count: u16,
+    pub is_public: u8,
+    #[length_remaining]
+    pub title: String,
+}
+
+/// Sent by the client to the map server to join a chat room.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00D9)]
+pub struct JoinChatRoomPacket {
+    pub chat_room_id: ChatRoomId,
+    /// Ignored for public chat rooms.
+    #[length(8)]
+    pub password: String,
+}
+
+/// Sent by the client to the map server to leave the current chat room.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00E3)]
+pub struct LeaveChatRoomPacket {}
+
+/// Sent by the map server to the client when the player receives an item for
+/// killing an MVP.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x010A)]
+pub struct MvpItemDropPacket {
+    pub item_id: ItemId,
+}
+
+/// Sent by the map server to the client when the player receives bonus
+/// experience for killing an MVP.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x010B)]
+pub struct MvpExpGainPacket {
+    pub experience: u32,
+}
+
+/// Mirrors `e_bossmap_info` of rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum BossInformationType {
+    Hidden,
+    Alive,
+    /// The boss just spawned, the client shows a message.
+    AliveWithMessage,
+    /// The boss is dead, the respawn timer is valid.
+    Dead,
+}
+
+/// Sent by the map server to the client while the player carries a boss
+/// locator (e.g. a Convex Mirror).
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0293)]
+pub struct MonsterBossLocatorPacket {
+    pub information_type: BossInformationType,
+    /// The coordinates are sent as four bytes each, use
+    /// [`MonsterBossLocatorPacket::position`] to get a [`TilePosition`].
+    pub x: u32,
+    pub y: u32,
+    pub minimum_respawn_hours: u16,
+    pub minimum_respawn_minutes: u16,
+    pub maximum_respawn_hours: u16,
+    pub maximum_respawn_minutes: u16,
+    #[length(51)]
+    pub monster_name: String,
+}
+
+impl MonsterBossLocatorPacket {
+    pub fn is_alive(&self) -> bool {
+        matches!(
+            self.information_type,
+            BossInformationType::Alive | BossInformationType::AliveWithMessage
+        )
+    }
+
+    pub fn position(&self) -> TilePosition {
+        TilePosition {
+            x: self.x as u16,
+            y: self.y as u16,
+        }
+    }
+}
+
+/// Sent by the client to the map server to open the roulette window. The
+/// server responds with a [`RouletteInfoPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A19)]
+pub struct OpenRoulettePacket {}
+
+/// Sent by the map server to the client when opening the roulette window.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A1A)]
+pub struct RouletteInfoPacket {
+    pub result: u8,
+    pub serial: u32,
+    pub stage: u8,
+    pub column: u8,
+    /// Item that is given in addition to the prize of the current stage.
+    pub additional_item_id: ItemId,
+    pub gold_points: u32,
+    pub silver_points: u32,
+    pub bronze_points: u32,
+}
+
+/// Sent by the client to the map server to spin the roulette.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0A1F)]
+pub struct GenerateRoulettePacket {}
+
+/// Mirrors `e_bg_queue_apply_type` of rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u16)]
+pub enum BattlegroundQueueType {
+    #[numeric_value(1)]
+    Solo,
+    #[numeric_value(2)]
+    Party,
+    #[numeric_value(4)]
+    Guild,
+}
+
+/// Sent by the client to the map server to join the queue of a battleground.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x08D7)]
+pub struct BattlegroundQueuePacket {
+    pub queue_type: BattlegroundQueueType,
+    #[length(24)]
+    pub queue_name: String,
+}
+
+/// Sent by the map server to the client when the position of the player in a
+/// battleground queue changes.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x08D9)]
+pub struct BattlegroundQueueStatusPacket {
+    #[length(24)]
+    pub queue_name: String,
+    pub position: u32,
+}
+
+/// Sent by the client to the map server to answer the invitation to a
+/// battleground once the queue is full.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x08E0)]
+pub struct BattlegroundAcceptPacket {
+    /// 1 to accept, 2 to decline.
+    pub result: u8,
+    #[length(24)]
+    pub queue_name: String,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0139)]
@@ -1563,6 +2833,7 @@ pub struct UpdateEntityHealthPointsPacket {
     pub maximum_health_points: u32,
 }
 
+/// Mirrors `e_damage_type` of rAthena.
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub enum DamageType {
@@ -1570,15 +2841,15 @@ pub enum DamageType {
     PickUpItem,
     SitDown,
     StandUp,
-    DamageEndure, // Not confirmed
-    Splash,       // Not confirmed
-    Skill,        // Not confirmed
-    RepeatDamage, // Not confirmed
+    DamageEndure,
+    Splash,
+    Skill,
+    RepeatDamage,
     MultiHitDamage,
-    MultiHitDamageEndure, // Not confirmed
+    MultiHitDamageEndure,
     CriticalHit,
     LuckyDodge,
-    TouchSkill, // Not confirmed
+    TouchSkill,
     CriticalMultiHit,
 }
 
@@ -1654,11 +2925,17 @@ pub struct SwitchCharacterSlotResponsePacket {
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0091)]
 pub struct ChangeMapPacket {
-    #[length(16)]
-    pub map_name: String,
+    pub map_name: FixedString<16>,
     pub position: TilePosition,
 }
 
+impl ChangeMapPacket {
+    /// Get the [normalized](normalize_map_name) name of the map.
+    pub fn normalized_map_name(&self) -> &str {
+        normalize_map_name(&self.map_name)
+    }
+}
+
 #[derive(Debug, Clone, ByteConvertable, PartialEq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub enum DisappearanceReason {
@@ -1677,12 +2954,38 @@ pub struct EntityDisappearedPacket {
     pub reason: DisappearanceReason,
 }
 
-#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+/// Kind of an entity in the entity appeared packets. Mirrors
+/// `e_packet_obj_type` of rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-#[header(0x09FD)]
-#[variable_length]
-pub struct MovingEntityAppearedPacket {
-    pub object_type: u8,
+pub enum ObjectType {
+    Player,
+    Npc,
+    Item,
+    Skill,
+    Unknown,
+    Monster,
+    Event,
+    Pet,
+    Homunculus,
+    Mercenary,
+    Elemental,
+    #[numeric_value(13)]
+    Abr,
+    Bionic,
+    #[fallback]
+    Other(u8),
+}
+
+// The entity appeared packets share most of their fields, but the fields that
+// differ are placed in between. The shared fields are therefore split into
+// three parts that keep the wire layout of each packet intact.
+
+/// First part of the fields shared by all entity appeared packets.
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct EntityAppearanceBase {
+    pub object_type: ObjectType,
     pub entity_id: EntityId,
     pub group_id: u32, // may be reversed - or completely wrong
     pub movement_speed: u16,
@@ -1694,7 +2997,12 @@ pub struct MovingEntityAppearedPacket {
     pub weapon: u32,
     pub shield: u32,
     pub accessory: u16,
-    pub move_start_time: u32,
+}
+
+/// Second part of the fields shared by all entity appeared packets.
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct EntityAppearanceLook {
     pub accessory2: u16,
     pub accessory3: u16,
     pub head_palette: u16,
@@ -1707,9 +3015,12 @@ pub struct MovingEntityAppearedPacket {
     pub virtue: u32,
     pub is_pk_mode_on: u8,
     pub sex: Sex,
-    pub position: WorldPosition2,
-    pub x_size: u8,
-    pub y_size: u8,
+}
+
+/// Last part of the fields shared by all entity appeared packets.
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct EntityAppearanceStatus {
     pub c_level: u16,
     pub font: u16,
     pub maximum_health_points: i32,
@@ -1720,6 +3031,20 @@ pub struct MovingEntityAppearedPacket {
     pub name: String,
 }
 
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x09FD)]
+#[variable_length]
+pub struct MovingEntityAppearedPacket {
+    pub base: EntityAppearanceBase,
+    pub move_start_time: u32,
+    pub look: EntityAppearanceLook,
+    pub position: WorldPosition2,
+    pub x_size: u8,
+    pub y_size: u8,
+    pub status: EntityAppearanceStatus,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0148)]
@@ -1735,41 +3060,12 @@ pub struct ResurrectionPacket {
 #[header(0x09FE)]
 #[variable_length]
 pub struct EntityAppearedPacket {
-    pub object_type: u8,
-    pub entity_id: EntityId,
-    pub group_id: u32, // may be reversed - or completely wrong
-    pub movement_speed: u16,
-    pub body_state: u16,
-    pub health_state: u16,
-    pub effect_state: u32,
-    pub job: u16,
-    pub head: u16,
-    pub weapon: u32,
-    pub shield: u32,
-    pub accessory: u16,
-    pub accessory2: u16,
-    pub accessory3: u16,
-    pub head_palette: u16,
-    pub body_palette: u16,
-    pub head_direction: u16,
-    pub robe: u16,
-    pub guild_id: u32, // may be reversed - or completely wrong
-    pub emblem_version: u16,
-    pub honor: u16,
-    pub virtue: u32,
-    pub is_pk_mode_on: u8,
-    pub sex: Sex,
+    pub base: EntityAppearanceBase,
+    pub look: EntityAppearanceLook,
     pub position: WorldPosition,
     pub x_size: u8,
     pub y_size: u8,
-    pub c_level: u16,
-    pub font: u16,
-    pub maximum_health_points: i32,
-    pub health_points: i32,
-    pub is_boss: u8,
-    pub body: u16,
-    #[length(24)]
-    pub name: String,
+    pub status: EntityAppearanceStatus,
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
@@ -1777,67 +3073,52 @@ pub struct EntityAppearedPacket {
 #[header(0x09FF)]
 #[variable_length]
 pub struct EntityAppeared2Packet {
-    pub object_type: u8,
-    pub entity_id: EntityId,
-    pub group_id: u32, // may be reversed - or completely wrong
-    pub movement_speed: u16,
-    pub body_state: u16,
-    pub health_state: u16,
-    pub effect_state: u32,
-    pub job: u16,
-    pub head: u16,
-    pub weapon: u32,
-    pub shield: u32,
-    pub accessory: u16,
-    pub accessory2: u16,
-    pub accessory3: u16,
-    pub head_palette: u16,
-    pub body_palette: u16,
-    pub head_direction: u16,
-    pub robe: u16,
-    pub guild_id: u32, // may be reversed - or completely wrong
-    pub emblem_version: u16,
-    pub honor: u16,
-    pub virtue: u32,
-    pub is_pk_mode_on: u8,
-    pub sex: Sex,
+    pub base: EntityAppearanceBase,
+    pub look: EntityAppearanceLook,
     pub position: WorldPosition,
     pub x_size: u8,
     pub y_size: u8,
     pub state: u8,
-    pub c_level: u16,
-    pub font: u16,
-    pub maximum_health_points: i32,
-    pub health_points: i32,
-    pub is_boss: u8,
-    pub body: u16,
-    #[length(24)]
-    pub name: String,
+    pub status: EntityAppearanceStatus,
 }
 
-#[derive(Clone, Copy, Debug, ByteConvertable, FixedByteSize)]
-#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
-#[numeric_type(u32)]
-pub enum SkillType {
-    #[numeric_value(0)]
-    Passive,
-    #[numeric_value(1)]
-    Attack,
-    #[numeric_value(2)]
-    Ground,
-    #[numeric_value(4)]
-    SelfCast,
-    #[numeric_value(16)]
-    Support,
-    #[numeric_value(32)]
-    Trap,
+bitflags::bitflags! {
+    /// How a skill is used. Mirrors `e_skill_inf` of rAthena. Passive skills
+    /// have no flags set.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+    pub struct SkillTypeFlags: u32 {
+        const ATTACK = 0b000001;
+        const GROUND = 0b000010;
+        const SELF_CAST = 0b000100;
+        const SUPPORT = 0b010000;
+        const TRAP = 0b100000;
+    }
+}
+
+impl FixedByteSize for SkillTypeFlags {
+    fn size_in_bytes() -> usize {
+        <<Self as bitflags::Flags>::Bits as FixedByteSize>::size_in_bytes()
+    }
+}
+
+impl FromBytes for SkillTypeFlags {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(Self::from_bits_retain)
+    }
+}
+
+impl ToBytes for SkillTypeFlags {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        self.bits().to_bytes(byte_writer)
+    }
 }
 
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct SkillInformation {
     pub skill_id: SkillId,
-    pub skill_type: SkillType,
+    pub skill_type: SkillTypeFlags,
     pub skill_level: SkillLevel,
     pub spell_point_cost: u16,
     pub attack_range: AttackRange,
@@ -1855,6 +3136,27 @@ pub struct UpdateSkillTreePacket {
     pub skill_information: Vec<SkillInformation>,
 }
 
+/// Sent by the client to the map server to spend a skill point on a skill.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0112)]
+pub struct IncreaseSkillLevelPacket {
+    pub skill_id: SkillId,
+}
+
+/// Sent by the map server to the client when the level of a single skill
+/// changes.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x010E)]
+pub struct SkillLevelUpdatePacket {
+    pub skill_id: SkillId,
+    pub skill_level: SkillLevel,
+    pub spell_point_cost: u16,
+    pub attack_range: AttackRange,
+    pub upgradable: u8,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct HotkeyData {
@@ -1911,12 +3213,27 @@ pub struct UpdateShowEquipPacket {
     pub open_equip_window: u8,
 }
 
+/// Mirrors `e_config_type` of rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u32)]
+pub enum ConfigurationType {
+    OpenEquipmentWindow,
+    Call,
+    PetAutoFeed,
+    HomunculusAutoFeed,
+    #[fallback]
+    Unknown(u32),
+}
+
+/// Sent by the map server to the client to update one of the player
+/// configurations.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x02D9)]
 pub struct UpdateConfigurationPacket {
-    pub config_type: u32,
-    pub value: u32, // only enabled and disabled ?
+    pub config_type: ConfigurationType,
+    pub value: Bool32,
 }
 
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
@@ -1976,6 +3293,33 @@ pub struct DialogMenuPacket {
     pub message: String,
 }
 
+impl DialogMenuPacket {
+    /// The menu options in the order they are displayed. Empty entries at the
+    /// end are dropped, but empty entries in between are kept so the
+    /// positions still match the indices expected by the server.
+    pub fn options(&self) -> Vec<&str> {
+        let mut options: Vec<&str> = self.message.split(':').collect();
+
+        while options.last().is_some_and(|option| option.is_empty()) {
+            options.pop();
+        }
+
+        options
+    }
+
+    /// Create the packet that selects the option at the given (zero based)
+    /// index of [`options`](Self::options). The server expects one based
+    /// indices, so this takes care of the conversion.
+    pub fn select(&self, index: usize) -> Option<ChooseDialogOptionPacket> {
+        if index >= self.options().len() {
+            return None;
+        }
+
+        let option = i8::try_from(index + 1).ok()?;
+        Some(ChooseDialogOptionPacket::new(self.npc_id, option))
+    }
+}
+
 #[derive(Debug, Clone, Copy, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[numeric_type(u32)]
@@ -3187,7 +4531,7 @@ pub struct DisplaySkillEffectNoDamagePacket {
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0983)]
 pub struct StatusChangePacket {
-    pub index: u16,
+    pub index: StatusEffect,
     pub entity_id: EntityId,
     pub state: u8,
     pub duration_in_milliseconds: u32,
@@ -3231,6 +4575,12 @@ pub struct HuntingObjective {
     pub current_count: u16,
 }
 
+impl HuntingObjective {
+    pub fn is_complete(&self) -> bool {
+        self.current_count >= self.total_count
+    }
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x08FE)]
@@ -3250,6 +4600,14 @@ pub struct HuntingQuestUpdateObjectivePacket {
     pub objective_details: Vec<HuntingObjective>,
 }
 
+impl HuntingQuestUpdateObjectivePacket {
+    /// Number of completed objectives and the total number of objectives.
+    pub fn progress_summary(&self) -> (usize, usize) {
+        let completed = self.objective_details.iter().filter(|objective| objective.is_complete()).count();
+        (completed, self.objective_details.len())
+    }
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x02B4)]
@@ -3271,6 +4629,12 @@ pub struct QuestDetails {
     pub mob_name: String,
 }
 
+impl QuestDetails {
+    pub fn is_complete(&self) -> bool {
+        self.kill_count >= self.total_count
+    }
+}
+
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct Quest {
@@ -3295,6 +4659,19 @@ pub struct QuestListPacket {
     pub quests: Vec<Quest>,
 }
 
+impl QuestListPacket {
+    /// Number of completed objectives and the total number of objectives
+    /// across all quests.
+    pub fn progress_summary(&self) -> (usize, usize) {
+        self.quests
+            .iter()
+            .flat_map(|quest| quest.objective_details.iter())
+            .fold((0, 0), |(completed, total), objective| {
+                (completed + objective.is_complete() as usize, total + 1)
+            })
+    }
+}
+
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[numeric_type(u32)]
@@ -3413,6 +4790,69 @@ pub struct ItemPickupPacket {
     pub enchantment_level: u8,
 }
 
+/// Sent by the map server to the client when an item drops on the ground,
+/// e.g. from a defeated monster.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0ADD)]
+pub struct ItemOnGroundAppearedPacket {
+    /// Ground items share their ids with entities.
+    pub object_id: EntityId,
+    pub item_id: ItemId,
+    pub item_type: u16,
+    pub is_identified: u8,
+    pub position: TilePosition,
+    /// Offset inside the tile, from 0 to 11.
+    pub sub_x: u8,
+    pub sub_y: u8,
+    pub amount: u16,
+    pub show_drop_effect: u8,
+    pub drop_effect_mode: u16,
+}
+
+/// Sent by the map server to the client when an item on the ground vanishes,
+/// either because it was picked up or because it expired.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00A1)]
+pub struct ItemOnGroundVanishedPacket {
+    pub object_id: EntityId,
+}
+
+/// Sent by the client to the map server to refine an item in the refine UI.
+/// The server responds with a [`RefineResultPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0AA3)]
+pub struct RefineItemRequestPacket {
+    pub index: InventoryIndex,
+    /// The material used for refining, e.g. Oridecon.
+    pub material_id: ItemId,
+    /// 1 to use a Blacksmith Blessing to prevent the item from breaking.
+    pub use_blacksmith_blessing: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u16)]
+pub enum RefineResult {
+    Success,
+    Fail,
+    Downgrade,
+    /// The refinement failed without the item breaking.
+    FailLevel,
+}
+
+/// Sent by the map server to the client after refining an item.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0188)]
+pub struct RefineResultPacket {
+    pub result: RefineResult,
+    pub index: InventoryIndex,
+    pub refinement_level: u16,
+}
+
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[numeric_type(u16)]
@@ -3549,7 +4989,7 @@ bitflags::bitflags! {
         const RIGTH_ACCESSORY = 128;
         const COSTUME_HEAD_TOP = 1024;
         const COSTUME_HEAD_MIDDLE = 2048;
-        const COSTUME_HEAD_LOWER = 4196;
+        const COSTUME_HEAD_LOWER = 4096;
         const COSTUME_GARMENT = 8192;
         const AMMO = 32768;
         const SHADOW_ARMOR = 65536;
@@ -3572,7 +5012,9 @@ impl FixedByteSize for EquipPosition {
 
 impl FromBytes for EquipPosition {
     fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
-        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(|raw| Self::from_bits(raw).expect("Invalid equip position"))
+        // Servers may send masks with bits that we don't know about, so we keep
+        // them instead of failing.
+        <Self as bitflags::Flags>::Bits::from_bytes(byte_reader).map(Self::from_bits_retain)
     }
 }
 
@@ -3590,6 +5032,29 @@ pub struct RequestEquipItemPacket {
     pub equip_position: EquipPosition,
 }
 
+/// Sent by the client to the map server to use a consumable item. The server
+/// responds with a [`UseItemResultPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0439)]
+pub struct UseItemPacket {
+    pub inventory_index: InventoryIndex,
+    pub account_id: AccountId,
+}
+
+/// Sent by the map server to the client after the player used an item.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x01C8)]
+pub struct UseItemResultPacket {
+    pub inventory_index: InventoryIndex,
+    pub item_id: ItemId,
+    pub account_id: AccountId,
+    /// Amount of the item that is left after using it.
+    pub amount: u16,
+    pub success: u8,
+}
+
 #[derive(Debug, Clone, ByteConvertable)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub enum RequestEquipItemStatus {
@@ -3753,20 +5218,95 @@ pub struct UseSkillSuccessPacket {
     pub destination_entity: EntityId,
     pub position: TilePosition,
     pub skill_id: SkillId,
-    pub element: u32,
+    pub element: Element,
     pub delay_time: u32,
     pub disposable: u8,
 }
 
+/// Element of a skill, attack or entity. Mirrors `e_element` of rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[numeric_type(u32)]
+pub enum Element {
+    Neutral,
+    Water,
+    Earth,
+    Fire,
+    Wind,
+    Poison,
+    Holy,
+    Shadow,
+    Ghost,
+    Undead,
+    /// Sent as `-1` when the element is not known up front, e.g. for skills
+    /// that use the element of the equipped weapon.
+    #[numeric_value(0xFFFFFFFF)]
+    Undefined,
+    /// Element that was added to the server but is not known to the client.
+    #[fallback]
+    Unknown(u32),
+}
+
+/// Sent by the map server to the client when an entity starts casting a
+/// skill. This is the layout used by older clients, newer clients receive
+/// [`UseSkillSuccessPacket`] instead.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x013E)]
+pub struct CastingPacket {
+    pub source_entity: EntityId,
+    pub destination_entity: EntityId,
+    pub position: TilePosition,
+    pub skill_id: SkillId,
+    pub property: Element,
+    /// Cast time in milliseconds.
+    pub cast_time: u32,
+}
+
+/// Sent by the map server to the client when an entity stops casting a skill
+/// before it completes.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x01B9)]
+pub struct CastCancelPacket {
+    pub entity_id: EntityId,
+}
+
+/// Mirrors `useskill_fail_cause` of rAthena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum SkillFailReason {
+    /// The skill level is too low.
+    Level,
+    NotEnoughSp,
+    NotEnoughHp,
+    /// A required item is missing, see [`SkillFailedPacket::item_id`].
+    NeedMoreItem,
+    Cooldown,
+    NotEnoughZeny,
+    WrongWeapon,
+    NeedRedGemstone,
+    NeedBlueGemstone,
+    Overweight,
+    Failed,
+    InvalidTarget,
+    #[fallback]
+    Unknown(u8),
+}
+
+/// Sent by the map server to the client when the player fails to use a skill.
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0110)]
-pub struct ToUseSkillSuccessPacket {
+pub struct SkillFailedPacket {
     pub skill_id: SkillId,
-    pub btype: i32,
+    /// Additional information depending on the reason, e.g. the required
+    /// amount of an item.
+    pub value: i32,
     pub item_id: ItemId,
+    /// Always 0 on rAthena.
     pub flag: u8,
-    pub cause: u8,
+    pub reason: SkillFailReason,
 }
 
 #[derive(Debug, Clone, ByteConvertable)]
@@ -4038,7 +5578,7 @@ pub struct FriendListPacket {
     pub friend_list: Vec<Friend>,
 }
 
-#[derive(Debug, Clone, ByteConvertable)]
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub enum OnlineState {
     Online,
@@ -4107,6 +5647,81 @@ pub struct PartyInvitePacket {
     pub party_name: String,
 }
 
+/// Sent by the client to the map server to invite another player to the
+/// party.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x02C4)]
+pub struct RequestPartyJoinPacket {
+    #[length(24)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum PartyRole {
+    Leader,
+    Member,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct PartyMemberInformation {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    #[length(24)]
+    pub name: String,
+    #[length(16)]
+    pub map_name: String,
+    pub role: PartyRole,
+    pub state: OnlineState,
+    pub job: u16,
+    pub base_level: u16,
+}
+
+/// Sent by the map server to the client when joining a party or when the
+/// members of the party change.
+///
+/// This is `ZC_GROUP_LIST` in the layout that rAthena sends from packet
+/// version 20171207 onwards, which includes the character id of each member.
+/// Older clients use `0x0A44` (20151007) and `0x00FB`. `0x0AF7` and `0x01B6`
+/// are not party packets: the first is a name response and the second is
+/// [`GuildInfoPacket`].
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0AE5)]
+#[variable_length]
+pub struct PartyInfoPacket {
+    #[length(24)]
+    pub party_name: String,
+    #[repeating_remaining]
+    pub members: Vec<PartyMemberInformation>,
+}
+
+/// Sent by the map server to the client to update the sharing options of the
+/// party.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x07D8)]
+pub struct PartyOptionsPacket {
+    /// 0 for each take, 1 for even share.
+    pub experience_sharing: u32,
+    /// 0 for each take, 1 for party share.
+    pub item_pickup_sharing: u8,
+    /// 0 for each take, 1 for party share.
+    pub item_division_sharing: u8,
+}
+
+/// Sent by the map server to the client to update the minimap position of a
+/// party member.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0107)]
+pub struct PartyMemberPositionPacket {
+    pub account_id: AccountId,
+    pub position: TilePosition,
+}
+
 #[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct ReputationEntry {
@@ -4168,6 +5783,84 @@ pub struct ClanOnlineCountPacket {
     pub maximum_members: u16,
 }
 
+/// Sent by the map server to the client when opening the guild window.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x01B6)]
+pub struct GuildInfoPacket {
+    pub guild_id: u32,
+    pub level: u32,
+    pub online_members: u32,
+    pub maximum_members: u32,
+    pub average_level: u32,
+    pub experience: u32,
+    pub maximum_experience: u32,
+    pub tax_points: u32,
+    pub honor: i32,
+    pub virtue: i32,
+    pub emblem_id: u32,
+    #[length(24)]
+    pub guild_name: String,
+    #[length(24)]
+    pub master_name: String,
+    #[length(16)]
+    pub territory: String,
+    pub zeny: u32,
+}
+
+#[derive(Debug, Clone, FixedByteSize, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct GuildMember {
+    pub account_id: AccountId,
+    pub character_id: CharacterId,
+    pub head: u16,
+    pub head_color: u16,
+    pub sex: u16,
+    pub job: u16,
+    pub level: u16,
+    pub contributed_experience: u32,
+    /// `1` if the member is online, `0` otherwise.
+    pub online: u32,
+    pub position: u32,
+    #[length(50)]
+    pub memo: String,
+    #[length(24)]
+    pub name: String,
+}
+
+/// Sent by the map server to the client when opening the member tab of the
+/// guild window.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0154)]
+#[variable_length]
+pub struct GuildMemberListPacket {
+    #[repeating_remaining]
+    pub members: Vec<GuildMember>,
+}
+
+/// Sent by the map server to the client in response to a
+/// [`RequestGuildEmblemPacket`]. The emblem data is a zlib compressed
+/// bitmap.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0152)]
+#[variable_length]
+pub struct GuildEmblemPacket {
+    pub guild_id: u32,
+    pub emblem_id: u32,
+    #[repeating_remaining]
+    pub emblem_data: Vec<u8>,
+}
+
+/// Sent by the client to the map server to request the emblem of a guild.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0151)]
+pub struct RequestGuildEmblemPacket {
+    pub guild_id: u32,
+}
+
 #[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 #[header(0x0192)]
@@ -4375,3 +6068,1911 @@ pub enum SellItemsResult {
 pub struct SellItemsResultPacket {
     pub result: SellItemsResult,
 }
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct VendingItem {
+    pub index: InventoryIndex,
+    pub amount: u16,
+    pub price: Price,
+}
+
+/// Sent by the client to the map server to open a vending shop with items
+/// from the cart.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x01B2)]
+#[variable_length]
+pub struct OpenVendingPacket {
+    #[length(80)]
+    pub shop_title: String,
+    /// Always 1 to open the shop.
+    #[new_value(1)]
+    pub open: u8,
+    #[repeating_remaining]
+    pub items: Vec<VendingItem>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct VendingItemInformation {
+    pub price: Price,
+    pub amount: u16,
+    pub index: InventoryIndex,
+    pub item_type: u8,
+    pub item_id: ItemId,
+    pub identified: u8,
+    pub damaged: u8,
+    pub refinement_level: u8,
+    pub slot: [u32; 4], // card ?
+    pub option_data: [ItemOptions; 5],
+    pub location: u32,
+    pub view_sprite: u16,
+    pub grade: u8,
+}
+
+/// Sent by the map server to the client as a response to opening the
+/// vending shop of another player.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0800)]
+#[variable_length]
+pub struct VendingItemsListPacket {
+    pub account_id: AccountId,
+    pub vending_id: u32,
+    #[repeating_remaining]
+    pub items: Vec<VendingItemInformation>,
+}
+
+#[derive(Debug, Clone, ByteConvertable, FixedByteSize)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub struct PurchaseVendingItemInformation {
+    pub amount: u16,
+    pub index: InventoryIndex,
+}
+
+/// Sent by the client to the map server to buy items from the vending shop of
+/// another player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0801)]
+#[variable_length]
+pub struct PurchaseFromVendingPacket {
+    pub account_id: AccountId,
+    pub vending_id: u32,
+    #[repeating_remaining]
+    pub items: Vec<PurchaseVendingItemInformation>,
+}
+
+/// Sent by the map server to the client when opening the storage and every
+/// time the number of stored items changes.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00F2)]
+pub struct StorageOpenedPacket {
+    pub current_count: u16,
+    pub maximum_count: u16,
+}
+
+/// Sent by the map server to the client when opening the storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0995)]
+#[variable_length]
+pub struct StorageItemListPacket {
+    #[length(24)]
+    pub storage_name: String,
+    #[repeating_remaining]
+    pub item_information: Vec<RegularItemInformation<StorageIndex>>,
+}
+
+/// Sent by the map server to the client when opening the storage.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0996)]
+#[variable_length]
+pub struct StorageEquippableItemListPacket {
+    #[length(24)]
+    pub storage_name: String,
+    #[repeating_remaining]
+    pub item_information: Vec<EquippableItemInformation<StorageIndex>>,
+}
+
+/// Sent by the client to the map server to move an item from the inventory
+/// into the storage.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0364)]
+pub struct MoveItemToStoragePacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server to move an item from the storage
+/// into the inventory.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0365)]
+pub struct MoveItemFromStoragePacket {
+    pub index: StorageIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server to move an item from the inventory
+/// into the cart.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0126)]
+pub struct MoveItemToCartPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server to move an item from the cart into
+/// the inventory. Cart indices have the same offset as inventory indices, so
+/// this uses an [`InventoryIndex`] as well.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0127)]
+pub struct MoveItemFromCartPacket {
+    pub index: InventoryIndex,
+    pub amount: u32,
+}
+
+/// Sent by the client to the map server to close the storage.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00F7)]
+pub struct CloseStoragePacket {}
+
+/// Sent by the map server to the client after the storage was closed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00F8)]
+pub struct StorageClosedPacket {}
+
+/// Sent by the map server to the client when another player requests a
+/// trade.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x01F4)]
+pub struct TradeRequestPacket {
+    #[length(24)]
+    pub name: String,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+/// Sent by the client to the map server to request a trade with another
+/// player.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00E4)]
+pub struct RequestTradePacket {
+    pub account_id: AccountId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum TradeResponse {
+    #[numeric_value(3)]
+    Accept,
+    #[numeric_value(4)]
+    Reject,
+}
+
+/// Sent by the client to the map server as a response to
+/// [`TradeRequestPacket`].
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00E6)]
+pub struct TradeResponsePacket {
+    pub response: TradeResponse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ByteConvertable)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum TradeRequestResult {
+    TooFarAway,
+    CharacterDoesNotExist,
+    Failed,
+    Accepted,
+    Cancelled,
+    Busy,
+}
+
+/// Sent by the map server to the client as a response to
+/// [`RequestTradePacket`] once the other player responded.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x01F5)]
+pub struct TradeRequestResultPacket {
+    pub result: TradeRequestResult,
+    pub character_id: CharacterId,
+    pub base_level: u16,
+}
+
+/// Index of an item in a trade. Zeny are added to the trade with index 0,
+/// items with their [`InventoryIndex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+pub enum TradeItemIndex {
+    Zeny,
+    Item(InventoryIndex),
+}
+
+impl FixedByteSize for TradeItemIndex {
+    fn size_in_bytes() -> usize {
+        u16::size_in_bytes()
+    }
+}
+
+impl FromBytes for TradeItemIndex {
+    fn from_bytes<Meta>(byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Self> {
+        let save_point = byte_reader.create_save_point();
+
+        if u16::from_bytes(byte_reader).trace::<Self>()? == 0 {
+            return Ok(Self::Zeny);
+        }
+
+        byte_reader.restore_save_point(save_point);
+        InventoryIndex::from_bytes(byte_reader).map(Self::Item).trace::<Self>()
+    }
+}
+
+impl ToBytes for TradeItemIndex {
+    fn to_bytes(&self, byte_writer: &mut ByteWriter) -> ConversionResult<usize> {
+        match self {
+            Self::Zeny => 0u16.to_bytes(byte_writer),
+            Self::Item(index) => index.to_bytes(byte_writer),
+        }
+        .trace::<Self>()
+    }
+}
+
+/// Sent by the client to the map server to add an item or zeny to the trade.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00E8)]
+pub struct AddItemToTradePacket {
+    pub index: TradeItemIndex,
+    pub amount: u32,
+}
+
+/// Sent by the map server to the client when the other player added an item
+/// to the trade. Zeny are sent with an item id of 0 and the amount of zeny.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x0B42)]
+pub struct TradeItemAddedPacket {
+    pub item_id: ItemId,
+    pub item_type: u8,
+    pub amount: u32,
+    pub identified: u8,
+    pub damaged: u8,
+    pub slot: [u32; 4], // card ?
+    pub option_data: [ItemOptions; 5],
+    pub refinement_level: u8,
+    pub grade: u8,
+}
+
+/// Sent by the client to the map server to lock in the current items of the
+/// trade.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00EB)]
+pub struct TradeConfirmPacket {}
+
+/// Sent by the map server to the client when one side of the trade locked in
+/// their items.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00EC)]
+pub struct TradeConfirmedPacket {
+    /// 0 if the player confirmed, 1 if the other player confirmed.
+    pub by_other_player: u8,
+}
+
+/// Sent by the client to the map server to cancel the trade.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00ED)]
+pub struct TradeCancelPacket {}
+
+/// Sent by the map server to the client when the trade was cancelled by
+/// either side.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00EE)]
+pub struct TradeCancelledPacket {}
+
+/// Sent by the client to the map server to complete the trade after both
+/// sides confirmed.
+#[derive(Debug, Clone, Packet, ClientPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00EF)]
+pub struct TradeCompletePacket {}
+
+/// Sent by the map server to the client once the trade was completed.
+#[derive(Debug, Clone, Packet, ServerPacket, MapServer)]
+#[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+#[header(0x00F0)]
+pub struct TradeCompletedPacket {
+    /// 0 on success, 1 on failure.
+    pub result: u8,
+}
+
+#[cfg(test)]
+mod conversion {
+    use ragnarok_bytes::{ByteReader, ByteWriter, FixedByteSize, FromBytes, ToBytes};
+
+    use crate::{
+        AccountId, AddItemToTradePacket, AttackRange, AuctionEntry, AuctionId, AuctionItemsListPacket, BattlegroundQueueStatusPacket,
+        Bool16, Bool32, BroadcastMessagePacket, CastCancelPacket, CastingPacket, ChangeDirectionPacket, ChangeMapPacket,
+        CharacterBanListPacket, CharacterId, CharacterSex, ChatRoomId, ChatRoomInfoPacket, ColorBGRA, ColorRGBA, ConfigurationType,
+        CreateCharacterPacket, DamagePacket3, DamageType, DialogMenuPacket, Direction, Element, EntityAppearanceBase, EntityAppearanceLook,
+        EntityAppearanceStatus, EntityAppeared2Packet, EntityAppearedPacket, EntityDirectionChangedPacket, EntityId, EquipPosition,
+        EquippableItemFlags, GlobalMessagePacket, GuildMember, GuildMemberListPacket, HairColor, HairStyle, HeadDirection,
+        HuntingObjective, HuntingQuestUpdateObjectivePacket, IncreaseSkillLevelPacket, IncreaseTraitStatPacket, InvalidMapNameError,
+        InventoryIndex, InventoryType, ItemId, ItemOnGroundAppearedPacket, Job, LargeTilePosition, LoginServerLoginPacket, MailFlags,
+        MailHeader, MailId, MailListPacket, MapServerLoginSuccessPacket, MovingEntityAppearedPacket, MvpExpGainPacket, ObjectType,
+        OnlineState, OpenVendingPacket, Packet, PacketExt, PacketHeader, PacketVersion, PartyMemberInformation, PartyRole, Price, Quest,
+        QuestDetails, QuestListPacket, RefineItemRequestPacket, RefineResult, RefineResultPacket, RegularItemFlags, RegularItemInformation,
+        RegularItemListPacket, RequestAchievementRewardPacket, RequestCharacterListSuccessPacket, RequestStatUpPacket,
+        RequestWarpToMapPacket, RouletteInfoPacket, SendWhisperPacket, Sex, SkillFailReason, SkillFailedPacket, SkillId, SkillLevel,
+        SkillLevelUpdatePacket, SkillTypeFlags, SpriteChangePacket, SpriteChangeType, StatType, StatUpType, StatusChangePacket,
+        StatusChangeSequencePacket, StatusEffect, StorageIndex, StorageItemListPacket, TilePosition, TradeItemIndex, TraitStatId,
+        UpdateConfigurationPacket, UseItemPacket, VendingItem, WhisperPacket, WhisperResult, WhisperResultPacket, WorldPosition,
+        WorldPosition2, normalize_map_name,
+    };
+
+    #[test]
+    fn bool16_non_zero() {
+        let mut byte_reader = ByteReader::without_metadata(&[0x00, 0x01, 0x00, 0x00]);
+
+        let value = Bool16::from_bytes(&mut byte_reader).unwrap();
+        assert!(bool::from(value));
+        assert!(!bool::from(Bool16::from_bytes(&mut byte_reader).unwrap()));
+
+        let mut byte_writer = ByteWriter::new();
+        value.to_bytes(&mut byte_writer).unwrap();
+        assert_eq!(byte_writer.into_inner(), [1, 0]);
+    }
+
+    #[test]
+    fn bool32_non_zero() {
+        let mut byte_reader = ByteReader::without_metadata(&[0xFF, 0x00, 0x00, 0x02]);
+
+        let value = Bool32::from_bytes(&mut byte_reader).unwrap();
+        assert_eq!(value, Bool32(true));
+        assert!(byte_reader.is_empty());
+
+        let mut byte_writer = ByteWriter::new();
+        Bool32::from(true).to_bytes(&mut byte_writer).unwrap();
+        Bool32::from(false).to_bytes(&mut byte_writer).unwrap();
+        assert_eq!(byte_writer.into_inner(), [1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn inventory_index_offset() {
+        let mut byte_reader = ByteReader::without_metadata(&[2, 0]);
+
+        assert_eq!(InventoryIndex::from_bytes(&mut byte_reader).unwrap(), InventoryIndex(0));
+    }
+
+    #[test]
+    fn inventory_index_underflow() {
+        for bytes in [[0, 0], [1, 0]] {
+            let mut byte_reader = ByteReader::without_metadata(&bytes);
+
+            assert!(InventoryIndex::from_bytes(&mut byte_reader).is_err());
+        }
+    }
+
+    #[test]
+    fn stat_type_round_trip() {
+        let stats = [
+            StatType::Weight(1),
+            StatType::MaximumWeight(2),
+            StatType::MovementSpeed(150),
+            StatType::BaseLevel(99),
+            StatType::JobLevel(50),
+            StatType::Karma(3),
+            StatType::Manner(4),
+            StatType::StatPoints(5),
+            StatType::SkillPoint(6),
+            StatType::Hit(7),
+            StatType::Flee1(8),
+            StatType::Flee2(9),
+            StatType::MaximumHealthPoints(10),
+            StatType::MaximumSpellPoints(11),
+            StatType::HealthPoints(12),
+            StatType::SpellPoints(13),
+            StatType::AttackSpeed(14),
+            StatType::Attack1(15),
+            StatType::Defense1(16),
+            StatType::MagicDefense1(17),
+            StatType::Attack2(18),
+            StatType::Defense2(19),
+            StatType::MagicDefense2(20),
+            StatType::Critical(21),
+            StatType::MagicAttack1(22),
+            StatType::MagicAttack2(23),
+            StatType::Zeny(u32::MAX),
+            StatType::BaseExperience(u64::MAX),
+            StatType::JobExperience(24),
+            StatType::NextBaseExperience(25),
+            StatType::NextJobExperience(26),
+            StatType::StrengthStatPointCost(27),
+            StatType::AgilityStatPointCost(28),
+            StatType::VitalityStatPointCost(29),
+            StatType::IntelligenceStatPointCost(30),
+            StatType::DexterityStatPointCost(31),
+            StatType::LuckStatPointCost(u8::MAX),
+            StatType::Strength(1, 2),
+            StatType::Agility(3, 4),
+            StatType::Vitality(5, 6),
+            StatType::Intelligence(7, 8),
+            StatType::Dexterity(9, 10),
+            StatType::Luck(130, -5),
+            StatType::CartInfo(1, 2, 3),
+            StatType::ActivityPoints(32),
+            StatType::TraitPoint(33),
+            StatType::MaximumActivityPoints(34),
+            StatType::Power(35, 36),
+            StatType::Stamina(37, 38),
+            StatType::Wisdom(39, 40),
+            StatType::Spell(41, 42),
+            StatType::Concentration(43, 44),
+            StatType::Creativity(45, 46),
+            StatType::PowerStatPointCost(47),
+            StatType::StaminaStatPointCost(48),
+            StatType::WisdomStatPointCost(49),
+            StatType::SpellStatPointCost(50),
+            StatType::ConcentrationStatPointCost(51),
+            StatType::CreativitySpellPointCost(52),
+            StatType::PhysicalAttack(53),
+            StatType::SpellMagicAttack(54),
+            StatType::Resistance(55),
+            StatType::MagicResistance(56),
+            StatType::HealingPlus(57),
+            StatType::CriticalDamageRate(58),
+        ];
+
+        for stat in stats {
+            let mut byte_writer = ByteWriter::new();
+            stat.to_bytes(&mut byte_writer).unwrap();
+
+            let bytes = byte_writer.into_inner();
+            let mut byte_reader = ByteReader::without_metadata(&bytes);
+
+            assert_eq!(StatType::from_bytes(&mut byte_reader).unwrap(), stat);
+            assert!(byte_reader.is_empty());
+        }
+    }
+
+    #[test]
+    fn stat_type_code_round_trip() {
+        let stats: Vec<StatType> = (0..=u16::MAX).filter_map(StatType::from_code).collect();
+
+        // One for every variant of `StatType`.
+        assert_eq!(stats.len(), 65);
+
+        for stat in stats {
+            assert_eq!(StatType::from_code(stat.status_code()), Some(stat));
+        }
+    }
+
+    #[test]
+    fn stat_type_codes() {
+        let mut byte_writer = ByteWriter::new();
+        StatType::MovementSpeed(0).to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner()[..2], [0, 0]);
+
+        let mut byte_writer = ByteWriter::new();
+        StatType::CartInfo(0, 0, 0).to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner()[..2], [99, 0]);
+    }
+
+    #[test]
+    fn party_member_information_round_trip() {
+        let member = PartyMemberInformation::new(
+            AccountId(2000001),
+            CharacterId(150000),
+            "Poring".to_owned(),
+            "prontera.gat".to_owned(),
+            PartyRole::Leader,
+            OnlineState::Offline,
+            4001,
+            99,
+        );
+
+        let mut byte_writer = ByteWriter::new();
+        member.to_bytes(&mut byte_writer).unwrap();
+
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes.len(), PartyMemberInformation::size_in_bytes());
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = PartyMemberInformation::from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.account_id, member.account_id);
+        assert_eq!(decoded.character_id, member.character_id);
+        assert_eq!(decoded.name, member.name);
+        assert_eq!(decoded.map_name, member.map_name);
+        assert_eq!(decoded.role, PartyRole::Leader);
+        assert!(matches!(decoded.state, OnlineState::Offline));
+        assert_eq!(decoded.job, 4001);
+        assert_eq!(decoded.base_level, 99);
+    }
+
+    #[test]
+    fn open_vending_packet_length() {
+        let items = vec![
+            VendingItem::new(InventoryIndex(0), 1, Price(1000)),
+            VendingItem::new(InventoryIndex(1), 20, Price(50)),
+            VendingItem::new(InventoryIndex(5), 300, Price(1_000_000)),
+        ];
+        let packet = OpenVendingPacket::new("Cheap potions".to_owned(), items);
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+
+        let bytes = byte_writer.into_inner();
+        // Header, packet length, shop title, open flag and three items.
+        let expected_length = 2 + 2 + 80 + 1 + 3 * 8;
+
+        assert_eq!(bytes.len(), expected_length);
+        assert_eq!(u16::from_le_bytes([bytes[2], bytes[3]]) as usize, expected_length);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = OpenVendingPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.shop_title, "Cheap potions");
+        assert_eq!(decoded.open, 1);
+        assert_eq!(decoded.items.len(), 3);
+        assert_eq!(decoded.items[2].index, InventoryIndex(5));
+        assert_eq!(decoded.items[2].price, Price(1_000_000));
+    }
+
+    #[test]
+    fn storage_item_list_shares_inventory_layout() {
+        let item = RegularItemInformation {
+            index: StorageIndex(0),
+            item_id: ItemId(501),
+            item_type: 0,
+            amount: 30,
+            equipped_position: EquipPosition::NONE,
+            slot: [0; 4],
+            hire_expiration_date: 0,
+            flags: RegularItemFlags::IDENTIFIED,
+        };
+
+        let mut item_bytes = ByteWriter::new();
+        item.to_bytes(&mut item_bytes).unwrap();
+
+        let item_bytes = item_bytes.into_inner();
+        // Storage indices are offset by one instead of two.
+        assert_eq!(item_bytes[0], 1);
+
+        let mut bytes = vec![0x95, 0x09, 0, 0];
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(&item_bytes);
+        bytes.extend_from_slice(&item_bytes);
+
+        let packet_length = bytes.len() as u16;
+        bytes[2..4].copy_from_slice(&packet_length.to_le_bytes());
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = StorageItemListPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.item_information.len(), 2);
+        assert_eq!(packet.item_information[0].index, StorageIndex(0));
+        assert_eq!(packet.item_information[0].item_id, ItemId(501));
+        assert_eq!(packet.item_information[0].amount, 30);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn cart_item_list() {
+        let item = RegularItemInformation {
+            index: InventoryIndex(4),
+            item_id: ItemId(501),
+            item_type: 0,
+            amount: 25,
+            equipped_position: EquipPosition::NONE,
+            slot: [0; 4],
+            hire_expiration_date: 0,
+            flags: RegularItemFlags::IDENTIFIED,
+        };
+
+        let mut byte_writer = ByteWriter::new();
+        InventoryType::Cart.to_bytes(&mut byte_writer).unwrap();
+        item.to_bytes(&mut byte_writer).unwrap();
+        let payload = byte_writer.into_inner();
+
+        let mut bytes = vec![0x09, 0x0B];
+        bytes.extend_from_slice(&(payload.len() as u16 + 4).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = RegularItemListPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(bytes[4], 1);
+        assert_eq!(packet.inventory_type, InventoryType::Cart);
+        assert_eq!(packet.item_information.len(), 1);
+        assert_eq!(packet.item_information[0].index, InventoryIndex(4));
+        assert_eq!(packet.item_information[0].item_id, ItemId(501));
+        assert_eq!(packet.item_information[0].amount, 25);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn hair_color_change() {
+        let mut bytes = vec![0xD7, 0x01];
+        bytes.extend_from_slice(&2000001u32.to_le_bytes());
+        bytes.push(6);
+        bytes.extend_from_slice(&4u32.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = SpriteChangePacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.account_id, AccountId(2000001));
+        assert_eq!(packet.sprite_type, SpriteChangeType::HairColor);
+        assert_eq!(packet.value, 4);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn job_bytes() {
+        let mut byte_writer = ByteWriter::new();
+        Job::Swordman.to_bytes(&mut byte_writer).unwrap();
+        Job::Summoner.to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner(), [1, 0, 0x7A, 0x10]);
+
+        // The discriminants are the values on the wire.
+        assert_eq!(Job::Swordman as u16, 1);
+        assert_eq!(Job::HighNovice as u16, 4001);
+        assert_eq!(Job::Summoner as u16, 4218);
+    }
+
+    #[test]
+    fn create_character_packet() {
+        let packet = CreateCharacterPacket::new(
+            "Novice".to_owned(),
+            2,
+            HairColor(3),
+            HairStyle(5),
+            Job::Novice,
+            CharacterSex::Female,
+        );
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes.len(), 36);
+        assert_eq!(bytes[26..33], [2, 3, 0, 5, 0, 0, 0]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = CreateCharacterPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.hair_color, HairColor(3));
+        assert_eq!(decoded.hair_style, HairStyle(5));
+        assert_eq!(decoded.start_job, Job::Novice);
+    }
+
+    #[test]
+    fn skill_type_flags() {
+        let decode = |value: u32| SkillTypeFlags::from_bytes(&mut ByteReader::without_metadata(&value.to_le_bytes())).unwrap();
+
+        assert!(decode(0).is_empty());
+        assert_eq!(decode(1), SkillTypeFlags::ATTACK);
+        assert_eq!(decode(32), SkillTypeFlags::TRAP);
+        assert_eq!(decode(3), SkillTypeFlags::ATTACK | SkillTypeFlags::GROUND);
+
+        let mut byte_writer = ByteWriter::new();
+        (SkillTypeFlags::SELF_CAST | SkillTypeFlags::SUPPORT)
+            .to_bytes(&mut byte_writer)
+            .unwrap();
+
+        assert_eq!(byte_writer.into_inner(), [20, 0, 0, 0]);
+    }
+
+    #[test]
+    fn character_sex_from_sex() {
+        assert_eq!(CharacterSex::try_from(Sex::Female).ok(), Some(CharacterSex::Female));
+        assert_eq!(CharacterSex::try_from(Sex::Male).ok(), Some(CharacterSex::Male));
+        assert!(CharacterSex::try_from(Sex::Both).is_err());
+        assert!(CharacterSex::try_from(Sex::Server).is_err());
+    }
+
+    #[test]
+    fn character_sex_bytes() {
+        let mut byte_writer = ByteWriter::new();
+        CharacterSex::Male.to_bytes(&mut byte_writer).unwrap();
+        Sex::Male.to_bytes(&mut byte_writer).unwrap();
+
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes, [1, 1]);
+        assert!(CharacterSex::from_bytes(&mut ByteReader::without_metadata(&[2])).is_err());
+    }
+
+    #[test]
+    fn regular_item_flags() {
+        let flags = [(RegularItemFlags::IDENTIFIED, 1), (RegularItemFlags::IN_ETC_TAB, 2)];
+
+        for (flag, value) in flags {
+            let mut byte_writer = ByteWriter::new();
+            flag.to_bytes(&mut byte_writer).unwrap();
+
+            assert_eq!(byte_writer.into_inner(), [value]);
+        }
+    }
+
+    #[test]
+    fn equippable_item_flags() {
+        let flags = [
+            (EquippableItemFlags::IDENTIFIED, 1),
+            (EquippableItemFlags::IS_BROKEN, 2),
+            (EquippableItemFlags::IN_ETC_TAB, 4),
+        ];
+
+        for (flag, value) in flags {
+            let mut byte_writer = ByteWriter::new();
+            flag.to_bytes(&mut byte_writer).unwrap();
+
+            assert_eq!(byte_writer.into_inner(), [value]);
+        }
+    }
+
+    #[test]
+    fn item_flags_keep_unused_bits() {
+        let flags = EquippableItemFlags::from_bytes(&mut ByteReader::without_metadata(&[0b1000_0011])).unwrap();
+
+        assert!(flags.contains(EquippableItemFlags::IDENTIFIED | EquippableItemFlags::IS_BROKEN));
+        assert!(!flags.contains(EquippableItemFlags::IN_ETC_TAB));
+        assert_eq!(flags.bits(), 0b1000_0011);
+    }
+
+    #[test]
+    fn equip_position_combined_mask() {
+        let position = EquipPosition::RIGHT_HAND | EquipPosition::GARMENT;
+
+        let mut byte_writer = ByteWriter::new();
+        position.to_bytes(&mut byte_writer).unwrap();
+
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes, [6, 0, 0, 0]);
+
+        let decoded = EquipPosition::from_bytes(&mut ByteReader::without_metadata(&bytes)).unwrap();
+
+        assert_eq!(decoded, position);
+        assert!(decoded.contains(EquipPosition::RIGHT_HAND));
+        assert!(decoded.contains(EquipPosition::GARMENT));
+        assert!(!decoded.contains(EquipPosition::LEFT_RIGHT_HAND));
+    }
+
+    #[test]
+    fn equip_position_costume_head_lower() {
+        let decoded = EquipPosition::from_bytes(&mut ByteReader::without_metadata(&[0x00, 0x10, 0, 0])).unwrap();
+
+        assert_eq!(decoded, EquipPosition::COSTUME_HEAD_LOWER);
+    }
+
+    #[test]
+    fn equip_position_unknown_bits() {
+        let decoded = EquipPosition::from_bytes(&mut ByteReader::without_metadata(&[0x02, 0, 0, 0x80])).unwrap();
+
+        assert!(decoded.contains(EquipPosition::RIGHT_HAND));
+        assert_eq!(decoded.bits(), 0x8000_0002);
+    }
+
+    #[test]
+    fn critical_hit_damage_packet() {
+        let bytes = [
+            0xC8, 0x08, // header
+            0x80, 0x84, 0x1E, 0x00, // source entity id
+            0xA0, 0x86, 0x01, 0x00, // destination entity id
+            0x10, 0x27, 0x00, 0x00, // client tick
+            0x20, 0x03, 0x00, 0x00, // attack duration
+            0xE0, 0x01, 0x00, 0x00, // damage delay
+            0xE7, 0x03, 0x00, 0x00, // damage amount
+            0x00, // is special damage
+            0x01, 0x00, // number of hits
+            0x0A, // damage type
+            0x00, 0x00, 0x00, 0x00, // damage amount 2
+        ];
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = DamagePacket3::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert!(matches!(packet.damage_type, DamageType::CriticalHit));
+        assert_eq!(packet.damage_amount, 999);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn fire_ball_casting_packet() {
+        let bytes = [
+            0x3E, 0x01, // header
+            0x80, 0x84, 0x1E, 0x00, // source entity id
+            0xA0, 0x86, 0x01, 0x00, // destination entity id
+            0x00, 0x00, // x
+            0x00, 0x00, // y
+            0x11, 0x00, // skill id
+            0x03, 0x00, 0x00, 0x00, // property
+            0xD0, 0x07, 0x00, 0x00, // cast time
+        ];
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = CastingPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.source_entity, EntityId(2000000));
+        assert_eq!(packet.destination_entity, EntityId(100000));
+        assert_eq!(packet.skill_id, SkillId(17));
+        assert_eq!(packet.property, Element::Fire);
+        assert_eq!(packet.cast_time, 2000);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn element_bytes() {
+        let cases = [
+            (Element::Neutral, [0, 0, 0, 0]),
+            (Element::Fire, [3, 0, 0, 0]),
+            (Element::Undead, [9, 0, 0, 0]),
+            (Element::Undefined, [0xFF, 0xFF, 0xFF, 0xFF]),
+            (Element::Unknown(10), [10, 0, 0, 0]),
+        ];
+
+        assert_eq!(Element::size_in_bytes(), 4);
+
+        for (element, bytes) in cases {
+            let mut byte_writer = ByteWriter::new();
+            element.to_bytes(&mut byte_writer).unwrap();
+            assert_eq!(byte_writer.into_inner(), bytes);
+
+            let mut byte_reader = ByteReader::without_metadata(&bytes);
+            assert_eq!(Element::from_bytes(&mut byte_reader).unwrap(), element);
+        }
+    }
+
+    #[test]
+    fn guild_member_list() {
+        let members = [
+            GuildMember::new(
+                AccountId(2000000),
+                CharacterId(150000),
+                1,
+                0,
+                1,
+                4,
+                99,
+                1200,
+                1,
+                0,
+                String::new(),
+                "Master".to_owned(),
+            ),
+            GuildMember::new(
+                AccountId(2000001),
+                CharacterId(150001),
+                3,
+                2,
+                0,
+                7,
+                55,
+                0,
+                0,
+                19,
+                "Away".to_owned(),
+                "Member".to_owned(),
+            ),
+        ];
+
+        let mut byte_writer = ByteWriter::new();
+        for member in &members {
+            member.to_bytes(&mut byte_writer).unwrap();
+        }
+        let payload = byte_writer.into_inner();
+
+        assert_eq!(GuildMember::size_in_bytes(), 104);
+        assert_eq!(payload.len(), 2 * 104);
+
+        let mut bytes = vec![0x54, 0x01];
+        bytes.extend_from_slice(&(payload.len() as u16 + 4).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = GuildMemberListPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.members.len(), 2);
+        assert_eq!(packet.members[0].name, "Master");
+        assert_eq!(packet.members[0].online, 1);
+        assert_eq!(packet.members[1].character_id, CharacterId(150001));
+        assert_eq!(packet.members[1].position, 19);
+        assert_eq!(packet.members[1].memo, "Away");
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn mismatched_header() {
+        let bytes = [0xFF, 0xB6, 0x01, 0x80, 0x84, 0x1E, 0x00];
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        u8::from_bytes(&mut byte_reader).unwrap();
+        let error = CastCancelPacket::packet_from_bytes(&mut byte_reader).unwrap_err();
+        let message = format!("{error:?}");
+
+        assert!(message.contains("0x01B9"));
+        assert!(message.contains("0x01B6"));
+        assert!(message.contains("offset 1"));
+    }
+
+    #[test]
+    fn header_is_written_first() {
+        assert_eq!(LoginServerLoginPacket::HEADER, PacketHeader(0x0064));
+
+        let packet = LoginServerLoginPacket::new("user".to_owned(), "password".to_owned());
+        let mut byte_writer = ByteWriter::new();
+        let written = packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(written, 55);
+        assert_eq!(bytes.len(), 55);
+        assert_eq!(bytes[..2], [0x64, 0x00]);
+    }
+
+    #[test]
+    fn mail_list() {
+        let mails = [
+            MailHeader::new(
+                MailId(7),
+                0,
+                MailFlags::TEXT | MailFlags::ZENY,
+                "Sender".to_owned(),
+                86400,
+                5,
+                "Hello".to_owned(),
+            ),
+            MailHeader::new(
+                MailId(8),
+                1,
+                MailFlags::TEXT | MailFlags::ITEM,
+                "Other".to_owned(),
+                3600,
+                7,
+                "Package".to_owned(),
+            ),
+        ];
+
+        let mut byte_writer = ByteWriter::new();
+        1u8.to_bytes(&mut byte_writer).unwrap();
+        for mail in &mails {
+            mail.to_bytes(&mut byte_writer).unwrap();
+        }
+        let payload = byte_writer.into_inner();
+
+        let mut bytes = vec![0xC2, 0x0A];
+        bytes.extend_from_slice(&(payload.len() as u16 + 4).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = MailListPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.is_end, 1);
+        assert_eq!(packet.mails.len(), 2);
+        assert_eq!(packet.mails[0].mail_id, MailId(7));
+        assert_eq!(packet.mails[0].title, "Hello");
+        assert!(packet.mails[0].flags.contains(MailFlags::ZENY));
+        assert_eq!(packet.mails[1].sender_name, "Other");
+        assert_eq!(packet.mails[1].title, "Package");
+        assert!(packet.mails[1].flags.contains(MailFlags::ITEM));
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn auction_items_list() {
+        let auction = |auction_id, seller_name: &str, item_id, current_bid| AuctionEntry {
+            auction_id: AuctionId(auction_id),
+            seller_name: seller_name.to_owned(),
+            item_id,
+            item_type: 4,
+            amount: 1,
+            is_identified: 1,
+            is_damaged: 0,
+            refinement_level: 7,
+            cards: [4001, 0, 0, 0],
+            current_bid: Price(current_bid),
+            buyout_price: Price(100000),
+            buyer_name: String::new(),
+            end_time: 1700000000,
+        };
+        let packet = AuctionItemsListPacket::new(3, vec![auction(10, "Seller", 1201, 5000), auction(11, "Other", 2301, 7500)]);
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let mut bytes = byte_writer.into_inner();
+
+        assert_eq!(AuctionEntry::size_in_bytes(), 83);
+        assert_eq!(bytes.len(), 12 + 2 * 83);
+        assert_eq!(bytes[..2], [0x52, 0x02]);
+        assert_eq!(u16::from_le_bytes([bytes[2], bytes[3]]) as usize, bytes.len());
+
+        // Data after the packet must not be consumed.
+        bytes.push(0xFF);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = AuctionItemsListPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.page_count, 3);
+        assert_eq!(packet.auction_count, 2);
+        assert_eq!(packet.auctions[0].auction_id, AuctionId(10));
+        assert_eq!(packet.auctions[0].seller_name, "Seller");
+        assert_eq!(packet.auctions[0].refinement_level, 7);
+        assert_eq!(packet.auctions[0].cards, [4001, 0, 0, 0]);
+        assert_eq!(packet.auctions[1].item_id, 2301);
+        assert_eq!(packet.auctions[1].current_bid, Price(7500));
+        assert_eq!(packet.auctions[1].buyer_name, "");
+        assert_eq!(byte_reader.remaining_bytes(), [0xFF]);
+    }
+
+    #[test]
+    fn whisper_round_trip() {
+        let packet = SendWhisperPacket::new("홍길동".to_owned(), "안녕하세요".to_owned());
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        // The name is padded to 24 bytes and the message is followed by a null byte.
+        assert_eq!(bytes.len(), 4 + 24 + 11);
+        assert_eq!(u16::from_le_bytes([bytes[2], bytes[3]]) as usize, bytes.len());
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = SendWhisperPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.target_name, "홍길동");
+        assert_eq!(decoded.message, "안녕하세요");
+        assert!(byte_reader.is_empty());
+
+        let packet = WhisperPacket::new("홍길동".to_owned(), Some(0), "hi".to_owned());
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = WhisperPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.sender_name, "홍길동");
+        assert_eq!(decoded.is_admin, Some(0));
+        assert_eq!(decoded.message, "hi");
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn whisper_result() {
+        let bytes = [0x98, 0x00, 0x01];
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = WhisperResultPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.result, WhisperResult::TargetOffline);
+    }
+
+    #[test]
+    fn chat_room_info() {
+        let mut bytes = vec![0xD7, 0x00, 0x00, 0x00];
+        bytes.extend_from_slice(&2000001u32.to_le_bytes());
+        bytes.extend_from_slice(&12u32.to_le_bytes());
+        bytes.extend_from_slice(&20u16.to_le_bytes());
+        bytes.extend_from_slice(&3u16.to_le_bytes());
+        bytes.push(1);
+        bytes.extend_from_slice(b"Help desk\0");
+
+        let packet_length = bytes.len() as u16;
+        bytes[2..4].copy_from_slice(&packet_length.to_le_bytes());
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = ChatRoomInfoPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.owner_id, AccountId(2000001));
+        assert_eq!(packet.chat_room_id, ChatRoomId(12));
+        assert_eq!(packet.member_limit, 20);
+        assert_eq!(packet.member_count, 3);
+        assert_eq!(packet.is_public, 1);
+        assert_eq!(packet.title, "Help desk");
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn mvp_exp_gain() {
+        let bytes = [0x0B, 0x01, 0x40, 0x42, 0x0F, 0x00];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = MvpExpGainPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.experience, 1_000_000);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn roulette_info() {
+        let bytes = [
+            0x1A, 0x0A, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x05, 0x1F, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+            0x0A, 0x00, 0x00, 0x00,
+        ];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = RouletteInfoPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.result, 0);
+        assert_eq!(packet.serial, 1);
+        assert_eq!(packet.stage, 2);
+        assert_eq!(packet.column, 5);
+        assert_eq!(packet.additional_item_id, ItemId(543));
+        assert_eq!(packet.gold_points, 0);
+        assert_eq!(packet.silver_points, 3);
+        assert_eq!(packet.bronze_points, 10);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn battleground_queue_status_round_trip() {
+        let packet = BattlegroundQueueStatusPacket::new("Tierra Gorge".to_owned(), 7);
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes.len(), 2 + 24 + 4);
+        assert_eq!(bytes[..2], [0xD9, 0x08]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = BattlegroundQueueStatusPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.queue_name, "Tierra Gorge");
+        assert_eq!(decoded.position, 7);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn change_direction_round_trip() {
+        let packet = ChangeDirectionPacket::new(HeadDirection::Left, Direction::SouthWest);
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes, [0x61, 0x03, 2, 0, 3]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = ChangeDirectionPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.head_direction, HeadDirection::Left);
+        assert_eq!(decoded.body_direction, Direction::SouthWest);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn entity_direction_changed_round_trip() {
+        let packet = EntityDirectionChangedPacket::new(EntityId(110000), HeadDirection::Right, Direction::West);
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes.len(), 9);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = EntityDirectionChangedPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.entity_id, EntityId(110000));
+        assert_eq!(decoded.head_direction, HeadDirection::Right);
+        assert_eq!(decoded.body_direction, Direction::West);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn outgoing_packet_length() {
+        // The packet length is not part of the packet struct, it is computed
+        // from the written data.
+        for message in ["", "hi", "Selling potions in Prontera!"] {
+            let mut byte_writer = ByteWriter::new();
+            GlobalMessagePacket::new(message.to_owned())
+                .packet_to_bytes(&mut byte_writer)
+                .unwrap();
+            let bytes = byte_writer.into_inner();
+
+            assert_eq!(bytes.len(), 4 + message.len() + 1);
+            assert_eq!(u16::from_le_bytes([bytes[2], bytes[3]]) as usize, bytes.len());
+        }
+    }
+
+    #[test]
+    fn update_configuration() {
+        let bytes = [0xD9, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = UpdateConfigurationPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.config_type, ConfigurationType::OpenEquipmentWindow);
+        assert_eq!(packet.value, Bool32(true));
+        assert!(byte_reader.is_empty());
+
+        let bytes = [0xD9, 0x02, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = UpdateConfigurationPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.config_type, ConfigurationType::Unknown(16));
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        assert_eq!(byte_writer.into_inner(), bytes);
+    }
+
+    #[test]
+    fn skill_failed_not_enough_sp() {
+        let bytes = [0x10, 0x01, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = SkillFailedPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.skill_id, SkillId(28));
+        assert_eq!(packet.reason, SkillFailReason::NotEnoughSp);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn item_on_ground_appeared() {
+        let mut bytes = vec![0xDD, 0x0A];
+        bytes.extend_from_slice(&5000u32.to_le_bytes());
+        bytes.extend_from_slice(&501u32.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        bytes.push(1);
+        bytes.extend_from_slice(&156u16.to_le_bytes());
+        bytes.extend_from_slice(&191u16.to_le_bytes());
+        bytes.extend_from_slice(&[3, 9]);
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = ItemOnGroundAppearedPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.object_id, EntityId(5000));
+        assert_eq!(packet.item_id, ItemId(501));
+        assert_eq!(packet.is_identified, 1);
+        assert_eq!(packet.position, TilePosition { x: 156, y: 191 });
+        assert_eq!((packet.sub_x, packet.sub_y), (3, 9));
+        assert_eq!(packet.amount, 2);
+        assert!(byte_reader.is_empty());
+    }
+
+    fn entity_appeared_bytes(header: u16, packet_length: u16, object_type: u8, entity_id: u32) -> Vec<u8> {
+        let mut bytes = vec![0; packet_length as usize];
+        bytes[0..2].copy_from_slice(&header.to_le_bytes());
+        bytes[2..4].copy_from_slice(&packet_length.to_le_bytes());
+        bytes[4] = object_type;
+        bytes[5..9].copy_from_slice(&entity_id.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn player_appeared() {
+        let bytes = entity_appeared_bytes(0x09FE, 107, 0, 150000);
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = EntityAppearedPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.base.object_type, ObjectType::Player);
+        assert_eq!(packet.base.entity_id, EntityId(150000));
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn monster_appeared() {
+        let bytes = entity_appeared_bytes(0x09FD, 114, 5, 110005);
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = MovingEntityAppearedPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.base.object_type, ObjectType::Monster);
+        assert_eq!(packet.base.entity_id, EntityId(110005));
+        assert!(byte_reader.is_empty());
+
+        let mut byte_reader = ByteReader::without_metadata(&[12]);
+        assert_eq!(ObjectType::from_bytes(&mut byte_reader).unwrap(), ObjectType::Other(12));
+    }
+
+    #[test]
+    fn entity_appeared_shared_fields() {
+        let base = EntityAppearanceBase::new(ObjectType::Npc, EntityId(110010), 0, 150, 0, 0, 0, 86, 0, 0, 0, 0);
+        let look = EntityAppearanceLook::new(0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, Sex::Male);
+        let status = EntityAppearanceStatus::new(0, 0, 100, 80, 0, 0, "Kafra Employee".to_owned());
+        let position = WorldPosition::new(150, 180, Direction::South);
+
+        let mut byte_writer = ByteWriter::new();
+        EntityAppearedPacket::new(base.clone(), look.clone(), position, 5, 5, status.clone())
+            .packet_to_bytes(&mut byte_writer)
+            .unwrap();
+        EntityAppeared2Packet::new(base.clone(), look.clone(), position, 5, 5, 1, status.clone())
+            .packet_to_bytes(&mut byte_writer)
+            .unwrap();
+        MovingEntityAppearedPacket::new(base, 1000, look, WorldPosition2::new(150, 180, 152, 180), 5, 5, status)
+            .packet_to_bytes(&mut byte_writer)
+            .unwrap();
+
+        let bytes = byte_writer.into_inner();
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+
+        let appeared = EntityAppearedPacket::packet_from_bytes(&mut byte_reader).unwrap();
+        let appeared2 = EntityAppeared2Packet::packet_from_bytes(&mut byte_reader).unwrap();
+        let moving = MovingEntityAppearedPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert!(byte_reader.is_empty());
+        assert_eq!(bytes.len(), 107 + 108 + 114);
+
+        for (base, look, status) in [
+            (&appeared.base, &appeared.look, &appeared.status),
+            (&appeared2.base, &appeared2.look, &appeared2.status),
+            (&moving.base, &moving.look, &moving.status),
+        ] {
+            assert_eq!(base.object_type, ObjectType::Npc);
+            assert_eq!(base.entity_id, EntityId(110010));
+            assert_eq!(base.movement_speed, 150);
+            assert_eq!(base.job, 86);
+            assert_eq!(look.head_direction, 2);
+            assert_eq!(look.sex, Sex::Male);
+            assert_eq!(status.maximum_health_points, 100);
+            assert_eq!(status.health_points, 80);
+            assert_eq!(status.name, "Kafra Employee");
+        }
+
+        assert_eq!(appeared.position, position);
+        assert_eq!(appeared2.state, 1);
+        assert_eq!(moving.move_start_time, 1000);
+    }
+
+    #[test]
+    fn refine_item_request_round_trip() {
+        let packet = RefineItemRequestPacket::new(InventoryIndex(4), ItemId(984), 1);
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes, [0xA3, 0x0A, 6, 0, 0xD8, 0x03, 0, 0, 1]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = RefineItemRequestPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.index, InventoryIndex(4));
+        assert_eq!(decoded.material_id, ItemId(984));
+        assert_eq!(decoded.use_blacksmith_blessing, 1);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn refine_success() {
+        let bytes = [0x88, 0x01, 0, 0, 6, 0, 7, 0];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = RefineResultPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.result, RefineResult::Success);
+        assert_eq!(packet.index, InventoryIndex(4));
+        assert_eq!(packet.refinement_level, 7);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn use_item_round_trip() {
+        let packet = UseItemPacket::new(InventoryIndex(0), AccountId(2000000));
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes, [0x39, 0x04, 2, 0, 0x80, 0x84, 0x1E, 0x00]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = UseItemPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.inventory_index, InventoryIndex(0));
+        assert_eq!(decoded.account_id, AccountId(2000000));
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn increase_skill_level_round_trip() {
+        let packet = IncreaseSkillLevelPacket::new(SkillId(5));
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes, [0x12, 0x01, 5, 0]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = IncreaseSkillLevelPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.skill_id, SkillId(5));
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn skill_level_update() {
+        let bytes = [0x0E, 0x01, 5, 0, 3, 0, 15, 0, 1, 0, 1];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = SkillLevelUpdatePacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.skill_id, SkillId(5));
+        assert_eq!(packet.skill_level, SkillLevel(3));
+        assert_eq!(packet.spell_point_cost, 15);
+        assert_eq!(packet.attack_range, AttackRange(1));
+        assert_eq!(packet.upgradable, 1);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn warp_to_map_name_length() {
+        let position = TilePosition { x: 150, y: 150 };
+
+        let packet = RequestWarpToMapPacket::try_new("prt_fild08.gat1".to_owned(), position).unwrap();
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes.len(), 2 + 16 + 4);
+        assert_eq!(bytes[17], 0);
+
+        assert_eq!(
+            RequestWarpToMapPacket::try_new("prt_fild08.gat12".to_owned(), position).unwrap_err(),
+            InvalidMapNameError::TooLong { length: 16 }
+        );
+    }
+
+    #[test]
+    fn warp_to_map_name_null() {
+        let position = TilePosition { x: 150, y: 150 };
+
+        assert_eq!(
+            RequestWarpToMapPacket::try_new("prontera\0.gat".to_owned(), position).unwrap_err(),
+            InvalidMapNameError::ContainsNull
+        );
+    }
+
+    #[test]
+    fn request_achievement_reward_round_trip() {
+        let packet = RequestAchievementRewardPacket::new(128001);
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+
+        let bytes = byte_writer.into_inner();
+        assert_eq!(bytes, [0x25, 0x0A, 0x01, 0xF4, 0x01, 0x00]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = RequestAchievementRewardPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.achievement_id, packet.achievement_id);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn normalized_map_names() {
+        assert_eq!(normalize_map_name("prontera.gat"), "prontera");
+        assert_eq!(normalize_map_name("prontera.rsw"), "prontera");
+        assert_eq!(normalize_map_name("prontera"), "prontera");
+        assert_eq!(normalize_map_name("prontera\0\0\0"), "prontera");
+    }
+
+    #[test]
+    fn normalized_map_name_of_padded_field() {
+        let mut bytes = vec![0x91, 0x00];
+        bytes.extend_from_slice(b"prontera.gat\0\0\0\0");
+        bytes.extend_from_slice(&[150, 0, 180, 0]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = ChangeMapPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.normalized_map_name(), "prontera");
+    }
+
+    #[test]
+    fn stat_type_status_code() {
+        assert_eq!(StatType::MovementSpeed(150).status_code(), 0);
+        assert_eq!(StatType::CartInfo(3, 100, 8000).status_code(), 99);
+
+        assert_eq!(StatType::from_code(0), Some(StatType::MovementSpeed(0)));
+        assert_eq!(StatType::from_code(99), Some(StatType::CartInfo(0, 0, 0)));
+        assert_eq!(StatType::from_code(10), None);
+    }
+
+    #[test]
+    fn repeating_remaining_trailing_bytes() {
+        // Two and a half 24 byte elements after the header and packet length.
+        let mut bytes = vec![0x0D, 0x02, 64, 0];
+        bytes.extend([0; 60]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let error = CharacterBanListPacket::packet_from_bytes(&mut byte_reader).unwrap_err();
+
+        assert!(format!("{error:?}").contains("trailing 12 bytes"));
+
+        bytes[2] = 52;
+        bytes.truncate(52);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = CharacterBanListPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.character_information.len(), 2);
+    }
+
+    #[test]
+    fn color_channel_order() {
+        let mut byte_reader = ByteReader::without_metadata(&[0x10, 0x20, 0x30, 0xFF]);
+        let bgra = ColorBGRA::from_bytes(&mut byte_reader).unwrap();
+        let rgba = ColorRGBA::from(bgra.clone());
+
+        assert_eq!(rgba, ColorRGBA {
+            red: 0x30,
+            green: 0x20,
+            blue: 0x10,
+            alpha: 0xFF,
+        });
+        assert_eq!(rgba.to_rgba_f32(), bgra.to_rgba_f32());
+        assert_eq!(rgba.to_rgba_f32(), [
+            0x30 as f32 / 255.0,
+            0x20 as f32 / 255.0,
+            0x10 as f32 / 255.0,
+            1.0
+        ]);
+        assert_eq!(ColorBGRA::from(rgba), bgra);
+    }
+
+    #[test]
+    fn dialog_menu_options() {
+        let packet = DialogMenuPacket::new(EntityId(110000), "Prontera:Geffen:Payon:Morroc".to_owned());
+
+        assert_eq!(packet.options(), ["Prontera", "Geffen", "Payon", "Morroc"]);
+
+        let choose = packet.select(2).unwrap();
+        assert_eq!(choose.npc_id, EntityId(110000));
+        assert_eq!(choose.option, 3);
+        assert!(packet.select(4).is_none());
+    }
+
+    #[test]
+    fn dialog_menu_empty_entries() {
+        let packet = DialogMenuPacket::new(EntityId(110000), "Yes::No:".to_owned());
+
+        assert_eq!(packet.options(), ["Yes", "", "No"]);
+        assert_eq!(packet.select(2).unwrap().option, 3);
+        assert!(packet.select(3).is_none());
+
+        let packet = DialogMenuPacket::new(EntityId(110000), ":".to_owned());
+        assert!(packet.options().is_empty());
+        assert!(packet.select(0).is_none());
+    }
+
+    #[test]
+    fn tile_adjacency() {
+        let center = TilePosition { x: 100, y: 100 };
+
+        for (x, y) in [
+            (99, 99),
+            (100, 99),
+            (101, 99),
+            (99, 100),
+            (101, 100),
+            (99, 101),
+            (100, 101),
+            (101, 101),
+        ] {
+            assert!(center.is_adjacent(TilePosition { x, y }));
+        }
+
+        assert!(!center.is_adjacent(center));
+        assert!(!center.is_adjacent(TilePosition { x: 102, y: 101 }));
+        assert_eq!(center.manhattan_distance(TilePosition { x: 101, y: 101 }), 2);
+    }
+
+    #[test]
+    fn tile_range() {
+        let attacker = TilePosition { x: 50, y: 50 };
+        let attack_range = 5;
+
+        assert!(attacker.chebyshev_distance(TilePosition { x: 55, y: 45 }) <= attack_range);
+        assert!(attacker.chebyshev_distance(TilePosition { x: 46, y: 55 }) <= attack_range);
+        assert!(attacker.chebyshev_distance(TilePosition { x: 56, y: 50 }) > attack_range);
+        assert_eq!(attacker.manhattan_distance(TilePosition { x: 55, y: 45 }), 10);
+
+        let attacker = LargeTilePosition { x: 50, y: 50 };
+        assert_eq!(attacker.chebyshev_distance(LargeTilePosition { x: 45, y: 53 }), 5);
+        assert_eq!(attacker.manhattan_distance(LargeTilePosition { x: 45, y: 53 }), 8);
+        assert!(attacker.is_adjacent(LargeTilePosition { x: 51, y: 49 }));
+    }
+
+    #[test]
+    fn quest_progress() {
+        let objective = |kill_count, total_count| QuestDetails::new(0, 0, 1002, 1, 99, kill_count, total_count, "Poring".to_owned());
+
+        let packet = QuestListPacket::new(vec![
+            Quest::new(1000, 1, 0, 0, vec![objective(10, 10), objective(3, 5)]),
+            Quest::new(1001, 1, 0, 0, vec![objective(7, 5)]),
+            Quest::new(1002, 0, 0, 0, Vec::new()),
+        ]);
+
+        assert_eq!(packet.progress_summary(), (2, 3));
+        assert_eq!(QuestListPacket::new(Vec::new()).progress_summary(), (0, 0));
+    }
+
+    #[test]
+    fn hunting_objective_progress() {
+        let packet = HuntingQuestUpdateObjectivePacket::new(3, vec![
+            HuntingObjective::new(1000, 1002, 10, 10),
+            HuntingObjective::new(1000, 1113, 5, 0),
+            HuntingObjective::new(1001, 1031, 20, 19),
+        ]);
+
+        assert!(packet.objective_details[0].is_complete());
+        assert!(!packet.objective_details[1].is_complete());
+        assert_eq!(packet.progress_summary(), (1, 3));
+    }
+
+    #[test]
+    fn status_effects() {
+        // Blessing on entity 150000 for 240 seconds.
+        let mut bytes = vec![0x83, 0x09, 10, 0, 0xF0, 0x49, 0x02, 0x00, 1];
+        bytes.extend(240000u32.to_le_bytes());
+        bytes.extend(240000u32.to_le_bytes());
+        bytes.extend([10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = StatusChangePacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.index, StatusEffect::Blessing);
+        assert_eq!(packet.entity_id, EntityId(150000));
+        assert_eq!(packet.duration_in_milliseconds, 240000);
+        assert_eq!(packet.value, [10, 0, 0]);
+
+        let mut byte_reader = ByteReader::without_metadata(&[0x96, 0x01, 12, 0, 0xF0, 0x49, 0x02, 0x00, 0]);
+        let packet = StatusChangeSequencePacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.index, StatusEffect::IncreaseAgi);
+
+        let mut byte_reader = ByteReader::without_metadata(&[0x0F, 0x27]);
+        let status_effect = StatusEffect::from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(status_effect, StatusEffect::Unknown(9999));
+
+        let mut byte_writer = ByteWriter::new();
+        status_effect.to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner(), [0x0F, 0x27]);
+    }
+
+    #[test]
+    fn map_server_login_success() {
+        let mut byte_writer = ByteWriter::new();
+        byte_writer.extend_from_slice(&[0xEB, 0x02, 0x10, 0x27, 0x00, 0x00]);
+        WorldPosition::new(150, 180, Direction::South).to_bytes(&mut byte_writer).unwrap();
+        byte_writer.extend_from_slice(&[5, 5, 0x01, 0x00]);
+
+        let bytes = byte_writer.into_inner();
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = MapServerLoginSuccessPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert!(byte_reader.is_empty());
+        assert_eq!(packet.client_tick.0, 10000);
+        assert_eq!((packet.position.x, packet.position.y), (150, 180));
+        assert_eq!(packet.x_size, 5);
+        assert_eq!(packet.y_size, 5);
+        assert_eq!(packet.font, 1);
+    }
+
+    #[test]
+    fn request_stat_up() {
+        let packet = RequestStatUpPacket::new(StatUpType::Strength { amount: 1 });
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes, [0xBB, 0x00, 13, 0, 1]);
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = RequestStatUpPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert!(byte_reader.is_empty());
+        assert_eq!(decoded.stat_type, StatUpType::Strength { amount: 1 });
+
+        let mut byte_reader = ByteReader::without_metadata(&[12, 0, 1]);
+        assert!(StatUpType::from_bytes(&mut byte_reader).is_err());
+    }
+
+    #[test]
+    fn increase_trait_stat() {
+        let packet = IncreaseTraitStatPacket::new(TraitStatId::Power, 1);
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+        let bytes = byte_writer.into_inner();
+
+        assert_eq!(bytes, [0x24, 0x0B, 219, 0, 1, 0]);
+        assert_eq!(u16::from_le_bytes([bytes[2], bytes[3]]), StatType::Power(0, 0).status_code());
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let decoded = IncreaseTraitStatPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert!(byte_reader.is_empty());
+        assert_eq!(decoded.stat_id, TraitStatId::Power);
+        assert_eq!(decoded.amount, 1);
+
+        let mut byte_reader = ByteReader::without_metadata(&[224, 0]);
+        assert_eq!(TraitStatId::from_bytes(&mut byte_reader).unwrap(), TraitStatId::Creativity);
+    }
+
+    /// A character in the character list as sent by rAthena. The robe palette
+    /// and the slot and name change counts were only added in 2011.
+    fn character_information_bytes(with_2011_fields: bool) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        // Character id, experience, zeny, job experience and job level.
+        bytes.extend_from_slice(&[0xF0, 0x49, 0x02, 0x00]);
+        bytes.extend_from_slice(&[0xE8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        bytes.extend_from_slice(&[0x88, 0x13, 0x00, 0x00]);
+        bytes.extend_from_slice(&[0xC8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        bytes.extend_from_slice(&[0x0A, 0x00, 0x00, 0x00]);
+        // Body state, health state, effect state, virtue and honor.
+        bytes.extend_from_slice(&[0; 20]);
+        // Stat points.
+        bytes.extend_from_slice(&[0x30, 0x00]);
+        // Health and spell points.
+        bytes.extend_from_slice(&[0xF4, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        bytes.extend_from_slice(&[0xF4, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        bytes.extend_from_slice(&[0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        bytes.extend_from_slice(&[0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        // Movement speed, job, head, body, weapon and base level.
+        bytes.extend_from_slice(&[0x96, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x14, 0x00]);
+        // Skill points, accessories, shield and palettes.
+        bytes.extend_from_slice(&[0; 14]);
+        // Name.
+        bytes.extend_from_slice(b"Poring\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0");
+        // Stats, character slot, hair color and whether the name was changed.
+        bytes.extend_from_slice(&[9, 5, 7, 1, 5, 1, 0, 1, 0, 0]);
+        // Map name and deletion date.
+        bytes.extend_from_slice(b"prontera.gat\0\0\0\0");
+        bytes.extend_from_slice(&[0; 4]);
+
+        if with_2011_fields {
+            // Robe palette, slot change count and name change count.
+            bytes.extend_from_slice(&[0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+        }
+
+        // Sex.
+        bytes.push(1);
+
+        bytes
+    }
+
+    #[test]
+    fn character_information_versions() {
+        for (version, with_2011_fields) in [(PacketVersion(20101124), false), (PacketVersion(20220406), true)] {
+            let character_bytes = character_information_bytes(with_2011_fields);
+            let mut bytes = vec![0x72, 0x0B];
+            bytes.extend_from_slice(&(character_bytes.len() as u16 * 2 + 4).to_le_bytes());
+            bytes.extend_from_slice(&character_bytes);
+            bytes.extend_from_slice(&character_bytes);
+
+            let mut byte_reader = ByteReader::with_metadata(&bytes, version);
+            let packet = RequestCharacterListSuccessPacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+            assert!(byte_reader.is_empty());
+            assert_eq!(packet.character_information.len(), 2);
+
+            let character = &packet.character_information[0];
+            assert_eq!(character.character_id, CharacterId(150000));
+            assert_eq!(character.name, "Poring");
+            assert_eq!(character.base_level, 20);
+            assert_eq!(character.map_name, "prontera.gat");
+            assert_eq!(character.sex, Sex::Male);
+
+            match with_2011_fields {
+                true => {
+                    assert_eq!(character.robe_palette, Some(0));
+                    assert_eq!(character.character_slot_change_count, Some(1));
+                    assert_eq!(character.character_name_change_count, Some(1));
+                }
+                false => {
+                    assert_eq!(character.robe_palette, None);
+                    assert_eq!(character.character_slot_change_count, None);
+                    assert_eq!(character.character_name_change_count, None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn add_item_to_trade_layout() {
+        // Captured CZ_ADD_EXCHANGE_ITEM for the item in inventory slot 3 and for
+        // 1000 zeny.
+        let item_bytes = [0xE8, 0x00, 0x05, 0x00, 0x0A, 0x00, 0x00, 0x00];
+        let zeny_bytes = [0xE8, 0x00, 0x00, 0x00, 0xE8, 0x03, 0x00, 0x00];
+
+        let mut byte_reader = ByteReader::without_metadata(&item_bytes);
+        let item = AddItemToTradePacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(item.index, TradeItemIndex::Item(InventoryIndex(3)));
+        assert_eq!(item.amount, 10);
+        assert!(byte_reader.is_empty());
+
+        let mut byte_reader = ByteReader::without_metadata(&zeny_bytes);
+        let zeny = AddItemToTradePacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(zeny.index, TradeItemIndex::Zeny);
+        assert_eq!(zeny.amount, 1000);
+        assert!(byte_reader.is_empty());
+
+        let mut byte_writer = ByteWriter::new();
+        item.packet_to_bytes(&mut byte_writer).unwrap();
+        zeny.packet_to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner(), [item_bytes, zeny_bytes].concat());
+    }
+
+    #[test]
+    fn fallback_variants_round_trip() {
+        let mut byte_reader = ByteReader::without_metadata(&[13, 14, 200]);
+
+        assert_eq!(ObjectType::from_bytes(&mut byte_reader).unwrap(), ObjectType::Abr);
+        assert_eq!(ObjectType::from_bytes(&mut byte_reader).unwrap(), ObjectType::Bionic);
+        assert_eq!(
+            SkillFailReason::from_bytes(&mut byte_reader).unwrap(),
+            SkillFailReason::Unknown(200)
+        );
+
+        let mut byte_writer = ByteWriter::new();
+        ObjectType::Abr.to_bytes(&mut byte_writer).unwrap();
+        SkillFailReason::Unknown(200).to_bytes(&mut byte_writer).unwrap();
+        StatusEffect::Unknown(9999).to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner(), [13, 200, 0x0F, 0x27]);
+        assert_eq!(StatusEffect::size_in_bytes(), 2);
+    }
+
+    #[test]
+    fn packet_length_matches() {
+        let bytes = [0x9A, 0x00, 0x0A, 0x00, b'h', b'e', b'l', b'l', b'o', 0x00, 0xFF];
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = BroadcastMessagePacket::packet_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet.message, "hello");
+        assert_eq!(byte_reader.remaining_bytes(), [0xFF]);
+    }
+
+    #[test]
+    fn packet_length_exceeds_data() {
+        let bytes = [0x9A, 0x00, 0x40, 0x00, b'h', b'e', b'l', b'l', b'o', 0x00];
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let error = BroadcastMessagePacket::packet_from_bytes(&mut byte_reader).unwrap_err();
+
+        assert!(error.is_byte_reader_too_short());
+    }
+
+    #[test]
+    fn packet_length_truncated() {
+        let bytes = [0x9A, 0x00, 0x03, 0x00, b'h', b'e', b'l', b'l', b'o', 0x00];
+
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let error = BroadcastMessagePacket::packet_from_bytes(&mut byte_reader).unwrap_err();
+
+        assert!(!error.is_byte_reader_too_short());
+        assert!(format!("{error:?}").contains("mismatched packet length"));
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::{Duration, Instant};
+
+use ragnarok_bytes::{ByteWriter, ConversionResult};
+
+use crate::{ClientPacket, PacketExt};
+
+/// Schedules keep-alive packets (packets marked with `#[ping]`) without
+/// depending on any async runtime.
+///
+/// The tracker doesn't read the clock itself, the caller passes the current
+/// [`Instant`] instead. This makes it usable from any kind of event loop.
+pub struct PingTracker<Ping, Factory>
+where
+    Ping: ClientPacket,
+    Factory: FnMut() -> Ping,
+{
+    interval: Duration,
+    last_sent: Instant,
+    factory: Factory,
+}
+
+impl<Ping, Factory> PingTracker<Ping, Factory>
+where
+    Ping: ClientPacket,
+    Factory: FnMut() -> Ping,
+{
+    /// Create a new tracker. `last_sent` is usually the time the connection
+    /// was established, so the first ping is sent one `interval` after that.
+    pub fn new(interval: Duration, last_sent: Instant, factory: Factory) -> Self {
+        Self {
+            interval,
+            last_sent,
+            factory,
+        }
+    }
+
+    /// The point in time at which the next ping should be sent.
+    pub fn next_ping(&self) -> Instant {
+        self.last_sent + self.interval
+    }
+
+    pub fn is_due(&self, now: Instant) -> bool {
+        now >= self.next_ping()
+    }
+
+    /// Create the ping packet if it is due.
+    pub fn poll(&mut self, now: Instant) -> Option<Ping> {
+        if !self.is_due(now) {
+            return None;
+        }
+
+        // If we are late we don't try to catch up, so the interval is measured
+        // from the time the ping was actually sent.
+        self.last_sent = now;
+        Some((self.factory)())
+    }
+
+    /// Write the ping packet **with the header** if it is due. Returns
+    /// `true` if a packet was written.
+    pub fn poll_to_bytes(&mut self, now: Instant, byte_writer: &mut ByteWriter) -> ConversionResult<bool> {
+        match self.poll(now) {
+            Some(packet) => packet.packet_to_bytes(byte_writer).map(|_| true),
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tracker {
+    use std::time::{Duration, Instant};
+
+    use ragnarok_bytes::ByteWriter;
+
+    use crate::{LoginServerKeepalivePacket, PingTracker};
+
+    const LOGIN_SERVER_INTERVAL: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn login_server_keepalive() {
+        let start = Instant::now();
+        let mut tracker = PingTracker::new(LOGIN_SERVER_INTERVAL, start, LoginServerKeepalivePacket::new);
+        let mut byte_writer = ByteWriter::new();
+
+        let almost = start + LOGIN_SERVER_INTERVAL - Duration::from_millis(1);
+        assert!(!tracker.poll_to_bytes(almost, &mut byte_writer).unwrap());
+        assert!(byte_writer.into_inner().is_empty());
+
+        let mut byte_writer = ByteWriter::new();
+        let boundary = start + LOGIN_SERVER_INTERVAL;
+        assert!(tracker.poll_to_bytes(boundary, &mut byte_writer).unwrap());
+
+        let bytes = byte_writer.into_inner();
+        assert_eq!(bytes.len(), 26);
+        assert_eq!(bytes[..2], [0x00, 0x02]);
+
+        assert_eq!(tracker.next_ping(), boundary + LOGIN_SERVER_INTERVAL);
+        assert!(tracker.poll(boundary + Duration::from_secs(30)).is_none());
+    }
+
+    #[test]
+    fn late_poll() {
+        let start = Instant::now();
+        let mut tracker = PingTracker::new(LOGIN_SERVER_INTERVAL, start, LoginServerKeepalivePacket::new);
+
+        let late = start + Duration::from_secs(150);
+        assert!(tracker.poll(late).is_some());
+        assert!(tracker.poll(late).is_none());
+        assert_eq!(tracker.next_ping(), late + LOGIN_SERVER_INTERVAL);
+    }
+}
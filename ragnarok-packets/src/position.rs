@@ -1,18 +1,55 @@
-use ragnarok_bytes::{ByteConvertable, ByteReader, ByteWriter, ConversionResult, FromBytes, ToBytes};
+use ragnarok_bytes::{ByteConvertable, ByteReader, ByteWriter, ConversionResult, FixedByteSize, FromBytes, ToBytes};
 
 use crate::TilePosition;
 
-#[derive(Debug, Copy, Clone, ByteConvertable)]
+/// Facing direction of an entity, in the order used on the wire. Mirrors
+/// `e_dir` of rAthena, so the directions go counter-clockwise starting at
+/// north. North is the positive y axis and east is the positive x axis of the
+/// tile grid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ByteConvertable, FixedByteSize)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub enum Direction {
     North = 0,
-    NorthEast = 1,
-    East = 2,
-    SouthEast = 3,
+    NorthWest = 1,
+    West = 2,
+    SouthWest = 3,
     South = 4,
-    SouthWest = 5,
-    West = 6,
-    NorthWest = 7,
+    SouthEast = 5,
+    East = 6,
+    NorthEast = 7,
+}
+
+impl Direction {
+    /// Tile delta of a single step in this direction, the same as `dirx` and
+    /// `diry` of rAthena.
+    pub fn to_offset(self) -> (i8, i8) {
+        match self {
+            Direction::North => (0, 1),
+            Direction::NorthEast => (1, 1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, -1),
+            Direction::South => (0, -1),
+            Direction::SouthWest => (-1, -1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, 1),
+        }
+    }
+
+    /// Inverse of [`Direction::to_offset`]. Returns `None` for offsets that
+    /// are not a single step.
+    pub fn from_offset(x: i8, y: i8) -> Option<Self> {
+        match (x, y) {
+            (0, 1) => Some(Direction::North),
+            (1, 1) => Some(Direction::NorthEast),
+            (1, 0) => Some(Direction::East),
+            (1, -1) => Some(Direction::SouthEast),
+            (0, -1) => Some(Direction::South),
+            (-1, -1) => Some(Direction::SouthWest),
+            (-1, 0) => Some(Direction::West),
+            (-1, 1) => Some(Direction::NorthWest),
+            _ => None,
+        }
+    }
 }
 
 impl From<Direction> for u16 {
@@ -27,13 +64,13 @@ impl From<u16> for Direction {
 
         match value {
             0 => Direction::North,
-            1 => Direction::NorthEast,
-            2 => Direction::East,
-            3 => Direction::SouthEast,
+            1 => Direction::NorthWest,
+            2 => Direction::West,
+            3 => Direction::SouthWest,
             4 => Direction::South,
-            5 => Direction::SouthWest,
-            6 => Direction::West,
-            7 => Direction::NorthWest,
+            5 => Direction::SouthEast,
+            6 => Direction::East,
+            7 => Direction::NorthEast,
             _ => unreachable!(),
         }
     }
@@ -45,22 +82,15 @@ pub struct InvalidDirectionError;
 impl TryFrom<[isize; 2]> for Direction {
     type Error = InvalidDirectionError;
 
-    fn try_from(value: [isize; 2]) -> Result<Self, Self::Error> {
-        match value {
-            [0, 1] => Ok(Direction::North),
-            [1, 1] => Ok(Direction::NorthEast),
-            [1, 0] => Ok(Direction::East),
-            [1, -1] => Ok(Direction::SouthEast),
-            [0, -1] => Ok(Direction::South),
-            [-1, -1] => Ok(Direction::SouthWest),
-            [-1, 0] => Ok(Direction::West),
-            [-1, 1] => Ok(Direction::NorthWest),
-            _ => Err(InvalidDirectionError),
-        }
+    fn try_from([x, y]: [isize; 2]) -> Result<Self, Self::Error> {
+        let x = i8::try_from(x).map_err(|_| InvalidDirectionError)?;
+        let y = i8::try_from(y).map_err(|_| InvalidDirectionError)?;
+
+        Self::from_offset(x, y).ok_or(InvalidDirectionError)
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
 pub struct WorldPosition {
     pub x: u16,
@@ -127,6 +157,9 @@ pub struct WorldPosition2 {
     pub y1: u16,
     pub x2: u16,
     pub y2: u16,
+    /// Sub-tile offset of the origin, packed as the x offset in the upper
+    /// and the y offset in the lower nibble. Unlike [`WorldPosition`] there
+    /// is no direction on the wire, see [`WorldPosition2::direction`].
     pub unknown: u16,
 }
 
@@ -141,17 +174,30 @@ impl WorldPosition2 {
         }
     }
 
+    /// Direction of the first step from the origin towards the destination.
+    /// Returns `None` if the origin and destination are the same tile.
+    pub fn direction(&self) -> Option<Direction> {
+        let x = (self.x2 as i32 - self.x1 as i32).signum() as i8;
+        let y = (self.y2 as i32 - self.y1 as i32).signum() as i8;
+
+        Direction::from_offset(x, y)
+    }
+
+    /// Split into origin and destination, both facing in the direction of the
+    /// movement.
     pub fn to_origin_destination(self) -> (WorldPosition, WorldPosition) {
+        let direction = self.direction().unwrap_or(Direction::North);
+
         (
             WorldPosition {
                 x: self.x1,
                 y: self.y1,
-                direction: Direction::North,
+                direction,
             },
             WorldPosition {
                 x: self.x2,
                 y: self.y2,
-                direction: Direction::North,
+                direction,
             },
         )
     }
@@ -177,9 +223,9 @@ impl ToBytes for WorldPosition2 {
             let mut bytes = [0; 6];
 
             bytes[0] = (self.x1 >> 2) as u8;
-            bytes[1] = ((self.x1 << 6) as u8) | ((self.y1 >> 4) as u8);
-            bytes[2] = ((self.y1 << 4) as u8) | ((self.x2 >> 6) as u8);
-            bytes[3] = ((self.x2 << 2) as u8) | ((self.y2 >> 8) as u8);
+            bytes[1] = ((self.x1 << 6) as u8) | (((self.y1 >> 4) & 0x3F) as u8);
+            bytes[2] = ((self.y1 << 4) as u8) | (((self.x2 >> 6) & 0xF) as u8);
+            bytes[3] = ((self.x2 << 2) as u8) | (((self.y2 >> 8) & 0x3) as u8);
             bytes[4] = self.y2 as u8;
             bytes[5] = self.unknown as u8;
 
@@ -192,9 +238,97 @@ impl ToBytes for WorldPosition2 {
 
 #[cfg(test)]
 mod conversion {
-    use ragnarok_bytes::{ByteWriter, FromBytes, ToBytes};
+    use ragnarok_bytes::{ByteWriter, FixedByteSize, FromBytes, ToBytes};
+
+    use crate::{Direction, WorldPosition, WorldPosition2};
+
+    #[test]
+    fn direction_offsets() {
+        let cases = [
+            (Direction::North, (0, 1)),
+            (Direction::NorthEast, (1, 1)),
+            (Direction::East, (1, 0)),
+            (Direction::SouthEast, (1, -1)),
+            (Direction::South, (0, -1)),
+            (Direction::SouthWest, (-1, -1)),
+            (Direction::West, (-1, 0)),
+            (Direction::NorthWest, (-1, 1)),
+        ];
+
+        for (direction, (x, y)) in cases {
+            assert_eq!(direction.to_offset(), (x, y));
+            assert_eq!(Direction::from_offset(x, y), Some(direction));
+            assert_eq!(Direction::try_from([x as isize, y as isize]).ok(), Some(direction));
+        }
+
+        assert_eq!(Direction::from_offset(0, 0), None);
+        assert_eq!(Direction::from_offset(2, 0), None);
+        assert!(Direction::try_from([300, 1]).is_err());
+    }
+
+    #[test]
+    fn direction_matches_rathena() {
+        // `dirx` and `diry` of rAthena, indexed by the direction on the wire.
+        let dirx = [0, -1, -1, -1, 0, 1, 1, 1];
+        let diry = [1, 1, 0, -1, -1, -1, 0, 1];
+
+        for value in 0..8u16 {
+            let direction = Direction::from(value);
+
+            assert_eq!(u16::from(direction), value);
+            assert_eq!(direction.to_offset(), (dirx[value as usize], diry[value as usize]));
+        }
+    }
+
+    #[test]
+    fn movement_direction_matches_entity() {
+        // The entity code faces each step from the previous tile towards the
+        // next one, the first step of a movement has to agree with that.
+        let cases = [
+            WorldPosition2::new(150, 180, 152, 180),
+            WorldPosition2::new(150, 180, 150, 170),
+            WorldPosition2::new(10, 10, 5, 20),
+            WorldPosition2::new(10, 10, 11, 9),
+        ];
 
-    use crate::{WorldPosition, WorldPosition2};
+        for position in cases {
+            let last_step = [position.x1 as isize, position.y1 as isize];
+            let next_step = [
+                last_step[0] + (position.x2 as isize - last_step[0]).signum(),
+                last_step[1] + (position.y2 as isize - last_step[1]).signum(),
+            ];
+            let entity_direction = Direction::try_from([next_step[0] - last_step[0], next_step[1] - last_step[1]]).unwrap();
+
+            assert_eq!(position.direction(), Some(entity_direction));
+        }
+    }
+
+    #[test]
+    fn direction_size() {
+        assert_eq!(Direction::size_in_bytes(), 1);
+
+        let mut byte_writer = ByteWriter::new();
+        Direction::SouthWest.to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner(), [3]);
+    }
+
+    #[test]
+    fn world_position_packing() {
+        // Packed the same way as rAthena's `WBUFPOS`: 10 bits x, 10 bits y and
+        // 4 bits direction. `West` is sent as direction 2 on the wire.
+        let position = WorldPosition::new(150, 200, Direction::West);
+        let bytes = [37, 140, 130];
+
+        let mut byte_writer = ByteWriter::new();
+        position.to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner().as_slice(), bytes.as_slice());
+
+        let mut byte_reader = ragnarok_bytes::ByteReader::without_metadata(&bytes);
+
+        assert_eq!(WorldPosition::from_bytes(&mut byte_reader).unwrap(), position);
+    }
 
     #[test]
     fn world_position() {
@@ -214,6 +348,51 @@ mod conversion {
         }
     }
 
+    #[test]
+    fn world_position_2_movement() {
+        // Moving two tiles east from (150, 180), starting in the center of the
+        // tile (sub-tile offset 8, 8).
+        let bytes = [37, 139, 66, 96, 180, 0x88];
+
+        let mut byte_reader = ragnarok_bytes::ByteReader::without_metadata(&bytes);
+        let position = WorldPosition2::from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!((position.x1, position.y1, position.x2, position.y2), (150, 180, 152, 180));
+        assert_eq!(position.direction(), Some(Direction::East));
+
+        let (origin, destination) = position.to_origin_destination();
+        assert_eq!(origin, WorldPosition::new(150, 180, Direction::East));
+        assert_eq!(destination, WorldPosition::new(152, 180, Direction::East));
+
+        let mut byte_writer = ByteWriter::new();
+        position.to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner().as_slice(), bytes.as_slice());
+        assert_eq!(WorldPosition2::new(10, 10, 10, 10).direction(), None);
+        assert_eq!(WorldPosition2::new(10, 10, 5, 20).direction(), Some(Direction::NorthWest));
+    }
+
+    #[test]
+    fn world_position_2_coordinates() {
+        // Coordinates are packed into 10 bits each, so 1023 is the largest value.
+        let cases = [(0, 0, 0, 0), (1023, 1023, 1023, 1023), (150, 180, 152, 180), (1, 1022, 512, 3)];
+
+        for (x1, y1, x2, y2) in cases {
+            let position = WorldPosition2::new(x1, y1, x2, y2);
+
+            let mut byte_writer = ByteWriter::new();
+            position.to_bytes(&mut byte_writer).unwrap();
+
+            let bytes = byte_writer.into_inner();
+            assert_eq!(bytes.len(), 6);
+
+            let mut byte_reader = ragnarok_bytes::ByteReader::without_metadata(&bytes);
+            let decoded = WorldPosition2::from_bytes(&mut byte_reader).unwrap();
+
+            assert_eq!((decoded.x1, decoded.y1, decoded.x2, decoded.y2), (x1, y1, x2, y2));
+        }
+    }
+
     #[test]
     fn world_position_2() {
         let cases = [
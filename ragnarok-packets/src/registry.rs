@@ -0,0 +1,448 @@
+//! Lookup of packet decoders by their header.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use ragnarok_bytes::{ByteReader, ConversionResult, ConversionResultExt, FromBytes};
+
+use crate::handler::DuplicateHandlerError;
+use crate::*;
+
+/// Object safe view of a decoded packet. Since it is also [`Any`], the
+/// concrete packet can be recovered by downcasting.
+pub trait AnyPacket: Debug + Any + Send {
+    /// Header of the decoded packet.
+    fn header(&self) -> PacketHeader;
+}
+
+impl<T> AnyPacket for T
+where
+    T: Packet,
+{
+    fn header(&self) -> PacketHeader {
+        T::HEADER
+    }
+}
+
+/// Fallback for packets with a header that is not registered in the
+/// [`PacketRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownPacket {
+    pub header: PacketHeader,
+    /// Since the size of an unknown packet can't be determined, this holds
+    /// everything that was left in the reader after the header.
+    pub bytes: Vec<u8>,
+}
+
+impl AnyPacket for UnknownPacket {
+    fn header(&self) -> PacketHeader {
+        self.header
+    }
+}
+
+pub type DecodeFunction<Meta> = Box<dyn Fn(&mut ByteReader<Meta>) -> ConversionResult<Box<dyn AnyPacket>>>;
+
+/// Maps packet headers to functions that decode the corresponding packet.
+///
+/// Unlike the [`PacketHandler`](crate::handler::PacketHandler), the registry
+/// doesn't convert packets into a common output type, which makes it useful
+/// for tools that only want to inspect the traffic.
+pub struct PacketRegistry<Meta>
+where
+    Meta: 'static,
+{
+    decoders: HashMap<PacketHeader, DecodeFunction<Meta>>,
+}
+
+impl<Meta> Default for PacketRegistry<Meta>
+where
+    Meta: 'static,
+{
+    fn default() -> Self {
+        Self {
+            decoders: Default::default(),
+        }
+    }
+}
+
+macro_rules! register_all {
+    ($registry:expr, [$($packet:ty),* $(,)?]) => {
+        $(
+            $registry.register::<$packet>().expect(concat!("duplicate header for ", stringify!($packet)));
+        )*
+    };
+}
+
+impl<Meta> PacketRegistry<Meta>
+where
+    Meta: 'static,
+{
+    /// Create a new registry with every packet defined in this crate.
+    pub fn with_all_known_packets() -> Self {
+        let mut registry = Self::default();
+
+        register_all!(registry, [
+            LoginServerLoginPacket,
+            LoginServerLoginSuccessPacket,
+            CharacterServerLoginSuccessPacket,
+            CharacterListPacket,
+            CharacterSlotPagePacket,
+            CharacterBanListPacket,
+            LoginPincodePacket,
+            Packet0b18,
+            MapServerLoginSuccessPacket,
+            LoginFailedPacket,
+            MapServerUnavailablePacket,
+            LoginFailedPacket2,
+            CharacterSelectionFailedPacket,
+            CharacterSelectionSuccessPacket,
+            CharacterCreationFailedPacket,
+            LoginServerKeepalivePacket,
+            CharacterServerLoginPacket,
+            MapServerLoginPacket,
+            Packet8302,
+            CreateCharacterPacket,
+            CreateCharacterSuccessPacket,
+            RequestCharacterListPacket,
+            RequestCharacterListSuccessPacket,
+            MapServerPingPacket,
+            RequestPlayerMovePacket,
+            RequestWarpToMapPacket,
+            EntityMovePacket,
+            EntityStopMovePacket,
+            PlayerMovePacket,
+            ChangeDirectionPacket,
+            EntityDirectionChangedPacket,
+            DeleteCharacterPacket,
+            CharacterDeletionFailedPacket,
+            CharacterDeletionSuccessPacket,
+            SelectCharacterPacket,
+            ServerMessagePacket,
+            MessageTablePacket,
+            RequestDetailsPacket,
+            RequestPlayerDetailsSuccessPacket,
+            RequestEntityDetailsSuccessPacket,
+            NewMailStatusPacket,
+            OpenMailboxPacket,
+            MailListPacket,
+            ReadMailPacket,
+            MailDetailPacket,
+            AuctionSearchPacket,
+            AuctionItemsListPacket,
+            AuctionBidPacket,
+            AchievementUpdatePacket,
+            AchievementListPacket,
+            RequestAchievementRewardPacket,
+            AchievementRewardResultPacket,
+            CriticalWeightUpdatePacket,
+            SpriteChangePacket,
+            InventoyStartPacket,
+            InventoyEndPacket,
+            RegularItemListPacket,
+            EquippableItemListPacket,
+            EquippableSwitchItemListPacket,
+            MapTypePacket,
+            Broadcast2MessagePacket,
+            BroadcastMessagePacket,
+            OverheadMessagePacket,
+            EntityMessagePacket,
+            DisplayEmotionPacket,
+            UpdateStatPacket,
+            StatusChangeSequencePacket,
+            InitialStatsPacket,
+            UpdateStatPacket1,
+            UpdateStatPacket2,
+            UpdateStatPacket3,
+            UpdateAttackRangePacket,
+            RequestStatUpPacket,
+            RequestStatUpResponsePacket,
+            IncreaseTraitStatPacket,
+            SwitchCharacterSlotPacket,
+            RequestActionPacket,
+            GlobalMessagePacket,
+            SendWhisperPacket,
+            WhisperPacket,
+            WhisperResultPacket,
+            CreateChatRoomPacket,
+            ChatRoomInfoPacket,
+            JoinChatRoomPacket,
+            LeaveChatRoomPacket,
+            MvpItemDropPacket,
+            MvpExpGainPacket,
+            MonsterBossLocatorPacket,
+            OpenRoulettePacket,
+            RouletteInfoPacket,
+            GenerateRoulettePacket,
+            BattlegroundQueuePacket,
+            BattlegroundQueueStatusPacket,
+            BattlegroundAcceptPacket,
+            RequestPlayerAttackFailedPacket,
+            UpdateEntityHealthPointsPacket,
+            DamagePacket1,
+            DamagePacket3,
+            ServerTickPacket,
+            RequestServerTickPacket,
+            SwitchCharacterSlotResponsePacket,
+            ChangeMapPacket,
+            EntityDisappearedPacket,
+            MovingEntityAppearedPacket,
+            ResurrectionPacket,
+            EntityAppearedPacket,
+            EntityAppeared2Packet,
+            UpdateSkillTreePacket,
+            IncreaseSkillLevelPacket,
+            SkillLevelUpdatePacket,
+            UpdateHotkeysPacket,
+            SetHotkeyData1Packet,
+            SetHotkeyData2Packet,
+            UpdatePartyInvitationStatePacket,
+            UpdateShowEquipPacket,
+            UpdateConfigurationPacket,
+            NavigateToMonsterPacket,
+            MarkMinimapPositionPacket,
+            NextButtonPacket,
+            CloseButtonPacket,
+            DialogMenuPacket,
+            DisplaySpecialEffectPacket,
+            DisplaySkillCooldownPacket,
+            DisplaySkillEffectAndDamagePacket,
+            DisplayPlayerHealEffect,
+            DisplaySkillEffectNoDamagePacket,
+            StatusChangePacket,
+            QuestNotificationPacket1,
+            HuntingQuestNotificationPacket,
+            HuntingQuestUpdateObjectivePacket,
+            QuestRemovedPacket,
+            QuestListPacket,
+            VisualEffectPacket,
+            DisplayGainedExperiencePacket,
+            DisplayImagePacket,
+            StateChangePacket,
+            ItemPickupPacket,
+            ItemOnGroundAppearedPacket,
+            ItemOnGroundVanishedPacket,
+            RefineItemRequestPacket,
+            RefineResultPacket,
+            RemoveItemFromInventoryPacket,
+            QuestEffectPacket,
+            NpcDialogPacket,
+            MapLoadedPacket,
+            CharacterServerKeepalivePacket,
+            StartDialogPacket,
+            NextDialogPacket,
+            CloseDialogPacket,
+            ChooseDialogOptionPacket,
+            RequestEquipItemPacket,
+            UseItemPacket,
+            UseItemResultPacket,
+            RequestEquipItemStatusPacket,
+            EquipAmmunitionPacket,
+            AmmunitionActionPacket,
+            RequestUnequipItemPacket,
+            RequestUnequipItemStatusPacket,
+            ParameterChangePacket,
+            RestartPacket,
+            RestartResponsePacket,
+            DisconnectResponsePacket,
+            UseSkillAtIdPacket,
+            UseSkillOnGroundPacket,
+            StartUseSkillPacket,
+            EndUseSkillPacket,
+            UseSkillSuccessPacket,
+            CastingPacket,
+            CastCancelPacket,
+            SkillFailedPacket,
+            NotifySkillUnitPacket,
+            NotifyGroundSkillPacket,
+            SkillUnitDisappearPacket,
+            AddFriendPacket,
+            RemoveFriendPacket,
+            NotifyFriendRemovedPacket,
+            FriendListPacket,
+            FriendOnlineStatusPacket,
+            FriendRequestPacket,
+            FriendRequestResponsePacket,
+            FriendRequestResultPacket,
+            PartyInvitePacket,
+            RequestPartyJoinPacket,
+            PartyInfoPacket,
+            PartyOptionsPacket,
+            PartyMemberPositionPacket,
+            ReputationPacket,
+            ClanInfoPacket,
+            ClanOnlineCountPacket,
+            GuildInfoPacket,
+            GuildMemberListPacket,
+            GuildEmblemPacket,
+            RequestGuildEmblemPacket,
+            ChangeMapCellPacket,
+            OpenMarketPacket,
+            ShopItemListPacket,
+            BuyOrSellPacket,
+            SelectBuyOrSellPacket,
+            BuyItemsPacket,
+            BuyShopItemsPacket,
+            BuyShopItemsResultPacket,
+            CloseShopPacket,
+            SellListPacket,
+            SellItemsPacket,
+            SellItemsResultPacket,
+            OpenVendingPacket,
+            VendingItemsListPacket,
+            PurchaseFromVendingPacket,
+            StorageOpenedPacket,
+            StorageItemListPacket,
+            StorageEquippableItemListPacket,
+            MoveItemToStoragePacket,
+            MoveItemFromStoragePacket,
+            MoveItemToCartPacket,
+            MoveItemFromCartPacket,
+            CloseStoragePacket,
+            StorageClosedPacket,
+            TradeRequestPacket,
+            RequestTradePacket,
+            TradeResponsePacket,
+            TradeRequestResultPacket,
+            AddItemToTradePacket,
+            TradeItemAddedPacket,
+            TradeConfirmPacket,
+            TradeConfirmedPacket,
+            TradeCancelPacket,
+            TradeCancelledPacket,
+            TradeCompletePacket,
+            TradeCompletedPacket,
+        ]);
+
+        registry
+    }
+
+    /// Register a new packet.
+    pub fn register<Packet>(&mut self) -> Result<(), DuplicateHandlerError>
+    where
+        Packet: crate::Packet,
+    {
+        let old_decoder = self.decoders.insert(
+            Packet::HEADER,
+            Box::new(|byte_reader| Packet::payload_from_bytes(byte_reader).map(|packet| Box::new(packet) as Box<dyn AnyPacket>)),
+        );
+
+        match old_decoder.is_some() {
+            true => Err(DuplicateHandlerError {
+                packet_header: Packet::HEADER,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    /// Check if a packet with the given header is registered.
+    pub fn is_registered(&self, header: PacketHeader) -> bool {
+        self.decoders.contains_key(&header)
+    }
+
+    /// Read a single packet including its header. If the header is not
+    /// registered, an [`UnknownPacket`] holding the rest of the data is
+    /// returned.
+    pub fn decode(&self, byte_reader: &mut ByteReader<Meta>) -> ConversionResult<Box<dyn AnyPacket>> {
+        let header = PacketHeader::from_bytes(byte_reader).trace::<Self>()?;
+
+        match self.decoders.get(&header) {
+            Some(decoder) => decoder(byte_reader),
+            None => Ok(Box::new(UnknownPacket {
+                header,
+                bytes: byte_reader.remaining_bytes(),
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod decode {
+    use std::any::Any;
+
+    use ragnarok_bytes::{ByteReader, ByteWriter};
+
+    use super::{PacketRegistry, UnknownPacket};
+    use crate::{
+        AccountId, AddItemToTradePacket, AuthToken, CharacterId, InventoryIndex, LoginServerLoginSuccessPacket, PacketExt, PacketHeader,
+        Sex, TradeCompletedPacket, TradeConfirmedPacket, TradeItemIndex, TradeRequestPacket, TradeResponse, TradeResponsePacket,
+    };
+
+    #[test]
+    fn known_packet() {
+        let packet = LoginServerLoginSuccessPacket::new(1, AccountId(2), 3, Sex::Male, AuthToken([4; 17]), Vec::new());
+
+        let mut byte_writer = ByteWriter::new();
+        packet.packet_to_bytes(&mut byte_writer).unwrap();
+
+        let bytes = byte_writer.into_inner();
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let registry = PacketRegistry::with_all_known_packets();
+        let decoded = registry.decode(&mut byte_reader).unwrap();
+
+        assert_eq!(decoded.header(), PacketHeader(0x0AC4));
+
+        let decoded = (decoded as Box<dyn Any>).downcast::<LoginServerLoginSuccessPacket>().unwrap();
+
+        assert_eq!(decoded.account_id, AccountId(2));
+        assert_eq!(decoded.auth_token.0, [4; 17]);
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn unknown_packet() {
+        let bytes = [0xFF, 0xFF, 1, 2, 3];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let registry = PacketRegistry::with_all_known_packets();
+        let decoded = registry.decode(&mut byte_reader).unwrap();
+        let decoded = (decoded as Box<dyn Any>).downcast::<UnknownPacket>().unwrap();
+
+        assert_eq!(*decoded, UnknownPacket {
+            header: PacketHeader(0xFFFF),
+            bytes: vec![1, 2, 3],
+        });
+    }
+
+    #[test]
+    fn trade_handshake() {
+        let mut byte_writer = ByteWriter::new();
+        TradeRequestPacket::new("Trader".to_owned(), CharacterId(150000), 99)
+            .packet_to_bytes(&mut byte_writer)
+            .unwrap();
+        TradeResponsePacket::new(TradeResponse::Accept)
+            .packet_to_bytes(&mut byte_writer)
+            .unwrap();
+        AddItemToTradePacket::new(TradeItemIndex::Item(InventoryIndex(3)), 5)
+            .packet_to_bytes(&mut byte_writer)
+            .unwrap();
+        AddItemToTradePacket::new(TradeItemIndex::Zeny, 1000)
+            .packet_to_bytes(&mut byte_writer)
+            .unwrap();
+        TradeConfirmedPacket::new(1).packet_to_bytes(&mut byte_writer).unwrap();
+        TradeCompletedPacket::new(0).packet_to_bytes(&mut byte_writer).unwrap();
+
+        let bytes = byte_writer.into_inner();
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let registry = PacketRegistry::with_all_known_packets();
+        let mut packets = Vec::new();
+
+        while !byte_reader.is_empty() {
+            packets.push(registry.decode(&mut byte_reader).unwrap() as Box<dyn Any>);
+        }
+
+        let [request, response, item, zeny, confirmed, completed] = packets.try_into().unwrap();
+
+        assert_eq!(request.downcast::<TradeRequestPacket>().unwrap().name, "Trader");
+        assert_eq!(
+            response.downcast::<TradeResponsePacket>().unwrap().response,
+            TradeResponse::Accept
+        );
+        assert_eq!(
+            item.downcast::<AddItemToTradePacket>().unwrap().index,
+            TradeItemIndex::Item(InventoryIndex(3))
+        );
+        assert_eq!(zeny.downcast::<AddItemToTradePacket>().unwrap().index, TradeItemIndex::Zeny);
+        assert_eq!(confirmed.downcast::<TradeConfirmedPacket>().unwrap().by_other_player, 1);
+        assert_eq!(completed.downcast::<TradeCompletedPacket>().unwrap().result, 0);
+    }
+}
@@ -0,0 +1,107 @@
+//! Decoding of raw packet captures.
+
+use ragnarok_bytes::{ByteReader, FromBytes};
+
+use crate::PacketHeader;
+use crate::registry::{AnyPacket, PacketRegistry, UnknownPacket};
+
+/// A packet read by [`decode_stream`].
+#[derive(Debug)]
+pub struct DecodedPacket {
+    /// Position of the packet header in the stream.
+    pub offset: usize,
+    pub packet: Box<dyn AnyPacket>,
+}
+
+/// Decode a stream of concatenated packets, for example a capture of the
+/// traffic of a single connection.
+///
+/// Packets are read until the stream is exhausted. Since the size of an
+/// unregistered packet can't be determined, the first unknown header ends the
+/// stream with an [`UnknownPacket`] holding the remaining bytes. The same
+/// happens if a registered packet fails to decode, e.g. because the capture
+/// is truncated. A trailing byte that can't hold a header is ignored.
+pub fn decode_stream(bytes: &[u8], registry: &PacketRegistry<()>) -> Vec<DecodedPacket> {
+    let mut byte_reader = ByteReader::without_metadata(bytes);
+    let mut packets = Vec::new();
+
+    while !byte_reader.is_empty() {
+        let offset = byte_reader.get_offset();
+        let save_point = byte_reader.create_save_point();
+
+        let packet = match registry.decode(&mut byte_reader) {
+            Ok(packet) => packet,
+            Err(..) => {
+                byte_reader.restore_save_point(save_point);
+
+                let Ok(header) = PacketHeader::from_bytes(&mut byte_reader) else {
+                    break;
+                };
+
+                Box::new(UnknownPacket {
+                    header,
+                    bytes: byte_reader.remaining_bytes(),
+                })
+            }
+        };
+
+        packets.push(DecodedPacket { offset, packet });
+    }
+
+    packets
+}
+
+#[cfg(test)]
+mod stream {
+    use std::any::Any;
+
+    use ragnarok_bytes::ByteWriter;
+
+    use super::decode_stream;
+    use crate::registry::{PacketRegistry, UnknownPacket};
+    use crate::{AccountId, AuthToken, ClientTick, LoginServerLoginSuccessPacket, PacketExt, PacketHeader, ServerTickPacket, Sex};
+
+    #[test]
+    fn login_success_and_server_tick() {
+        let mut byte_writer = ByteWriter::new();
+        LoginServerLoginSuccessPacket::new(1, AccountId(2), 3, Sex::Female, AuthToken([5; 17]), Vec::new())
+            .packet_to_bytes(&mut byte_writer)
+            .unwrap();
+        ServerTickPacket::new(ClientTick(1234)).packet_to_bytes(&mut byte_writer).unwrap();
+
+        let bytes = byte_writer.into_inner();
+        let registry = PacketRegistry::with_all_known_packets();
+        let packets = decode_stream(&bytes, &registry);
+
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0].offset, 0);
+        assert_eq!(packets[1].offset, bytes.len() - 6);
+
+        let [login_success, server_tick] = packets.try_into().unwrap();
+        let login_success = (login_success.packet as Box<dyn Any>)
+            .downcast::<LoginServerLoginSuccessPacket>()
+            .unwrap();
+        let server_tick = (server_tick.packet as Box<dyn Any>).downcast::<ServerTickPacket>().unwrap();
+
+        assert_eq!(login_success.account_id, AccountId(2));
+        assert_eq!(server_tick.client_tick.0, 1234);
+    }
+
+    #[test]
+    fn unknown_gap() {
+        let mut byte_writer = ByteWriter::new();
+        ServerTickPacket::new(ClientTick(1)).packet_to_bytes(&mut byte_writer).unwrap();
+
+        let mut bytes = byte_writer.into_inner();
+        bytes.extend_from_slice(&[0xFF, 0xFF, 1, 2, 3]);
+
+        let registry = PacketRegistry::with_all_known_packets();
+        let [_, unknown] = decode_stream(&bytes, &registry).try_into().unwrap();
+        let unknown = (unknown.packet as Box<dyn Any>).downcast::<UnknownPacket>().unwrap();
+
+        assert_eq!(*unknown, UnknownPacket {
+            header: PacketHeader(0xFFFF),
+            bytes: vec![1, 2, 3],
+        });
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::{Display, Formatter};
+
+use ragnarok_bytes::ByteReader;
+
+/// Protocol version of the server, following the `PACKETVER` scheme of
+/// rAthena (e.g. `20220406`).
+///
+/// Packet fields can be restricted to specific versions with the
+/// `packet_version_smaller(..)` and `packet_version_equals_or_above(..)`
+/// attributes. Restricted fields have to be of type `Option<T>` and are only
+/// read if the version of the [`ByteReader`] matches. Just like the versions of
+/// file formats, `smaller` is exclusive and `equals_or_above` is inclusive, so
+/// every version matches exactly one of the two.
+///
+/// The version is passed as the metadata of the [`ByteReader`]. If the reader
+/// carries any other metadata, the [`PacketVersion::LATEST`] layout is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketVersion(pub u32);
+
+impl PacketVersion {
+    /// The newest version supported. Packets are defined in their newest
+    /// layout, so this will include every field that is restricted with
+    /// `packet_version_equals_or_above`.
+    pub const LATEST: Self = Self(u32::MAX);
+
+    /// Get the packet version from the metadata of the [`ByteReader`].
+    pub fn from_metadata<Meta>(byte_reader: &ByteReader<Meta>) -> Self {
+        byte_reader.get_metadata::<Self, Self>().copied().unwrap_or(Self::LATEST)
+    }
+
+    pub fn smaller(&self, version: u32) -> bool {
+        self.0 < version
+    }
+
+    pub fn equals_or_above(&self, version: u32) -> bool {
+        self.0 >= version
+    }
+}
+
+impl Display for PacketVersion {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod restriction {
+    use ragnarok_bytes::{ByteReader, ByteWriter};
+
+    use crate::{Packet, PacketVersion};
+
+    #[derive(Debug, Clone, PartialEq, Packet)]
+    #[cfg_attr(feature = "interface", derive(rust_state::RustState, korangar_interface::element::StateElement))]
+    #[header(0xFFFF)]
+    struct VersionedPacket {
+        pub always: u16,
+        #[packet_version_smaller(20141016)]
+        pub before: Option<u8>,
+        #[packet_version_equals_or_above(20141016)]
+        pub after: Option<u32>,
+    }
+
+    const OLD_VERSION: PacketVersion = PacketVersion(20130807);
+    const NEW_VERSION: PacketVersion = PacketVersion(20141016);
+
+    #[test]
+    fn old_version() {
+        let bytes = [1, 0, 2];
+        let mut byte_reader = ByteReader::with_metadata(&bytes, OLD_VERSION);
+        let packet = VersionedPacket::payload_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet, VersionedPacket::new(1, Some(2), None));
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn new_version() {
+        let bytes = [1, 0, 3, 0, 0, 0];
+        let mut byte_reader = ByteReader::with_metadata(&bytes, NEW_VERSION);
+        let packet = VersionedPacket::payload_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet, VersionedPacket::new(1, None, Some(3)));
+        assert!(byte_reader.is_empty());
+    }
+
+    #[test]
+    fn latest_without_metadata() {
+        let bytes = [1, 0, 3, 0, 0, 0];
+        let mut byte_reader = ByteReader::without_metadata(&bytes);
+        let packet = VersionedPacket::payload_from_bytes(&mut byte_reader).unwrap();
+
+        assert_eq!(packet, VersionedPacket::new(1, None, Some(3)));
+    }
+
+    #[test]
+    fn skip_missing_fields() {
+        let packet = VersionedPacket::new(1, Some(2), None);
+        let mut byte_writer = ByteWriter::new();
+        packet.payload_to_bytes(&mut byte_writer).unwrap();
+
+        assert_eq!(byte_writer.into_inner(), [1, 0, 2]);
+    }
+}